@@ -1,12 +1,13 @@
 use std::f32::consts::PI;
 
 use copper_engine::{
+    assets::AssetServer,
     blank_on_awake, blank_tick_update, engine,
     input::InputManager,
-    math::Vector3,
-    object::{model_loader::get_obj, GameObject, Material},
+    math::{Transform, Vector3},
+    object::{GameObject, Material},
     structs::Scene,
-    Camera,
+    Camera, EngineConfig,
 };
 
 fn main() {
@@ -21,66 +22,59 @@ fn main() {
         blank_on_awake,
     );
 
+    // Every ball shares one model, uploaded to the GPU once.
+    let mut asset_server = AssetServer::new();
+    let ball = asset_server.load_model("models/ico.obj").unwrap();
+
+    let mut pink_material = Material::new([1.0, 0.2, 0.3]);
+    pink_material.albedo_texture =
+        Some(asset_server.load_texture("textures/debug_map.png").unwrap());
+
     let pink_ball = GameObject::new(
         "Pink Ball",
-        get_obj("models/ico.obj"),
-        [10.0, 0.0, 0.0],
-        [0.0, 0.0, 0.0],
-        [1.0, 1.0, 1.0],
-        Material {
-            ambient_color: [1.0, 0.2, 0.3],
-        },
+        ball.clone(),
+        Transform::from_translation(Vector3::new(10.0, 0.0, 0.0)),
+        pink_material,
         blank_tick_update,
         blank_on_awake,
     );
 
     let blue_ball = GameObject::new(
         "Blue Ball",
-        get_obj("models/ico.obj"),
-        [0.0, 0.0, -10.0],
-        [0.0, 0.0, 0.0],
-        [1.0, 1.0, 1.0],
-        Material {
-            ambient_color: [0.0, 0.2, 0.3],
-        },
+        ball.clone(),
+        Transform::from_translation(Vector3::new(0.0, 0.0, -10.0)),
+        Material::new([0.0, 0.2, 0.3]),
         blank_tick_update,
         blank_on_awake,
     );
 
     let green_ball = GameObject::new(
         "Dragon2",
-        get_obj("models/ico.obj"),
-        [0.0, 0.0, 10.0],
-        [0.0, 0.0, 0.0],
-        [1.0, 1.0, 1.0],
-        Material {
-            ambient_color: [0.0, 1.0, 0.0],
-        },
+        ball.clone(),
+        Transform::from_translation(Vector3::new(0.0, 0.0, 10.0)),
+        Material::new([0.0, 1.0, 0.0]),
         blank_tick_update,
         blank_on_awake,
     );
 
     let purple_ball = GameObject::new(
         "Ball2",
-        get_obj("models/ico.obj"),
-        [-10.0, 0.0, 0.0],
-        [0.0, 0.0, 0.0],
-        [1.0, 1.0, 1.0],
-        Material {
-            ambient_color: [1.0, 0.0, 1.0],
-        },
+        ball,
+        Transform::from_translation(Vector3::new(-10.0, 0.0, 0.0)),
+        Material::new([1.0, 0.0, 1.0]),
         blank_tick_update,
         blank_on_awake,
     );
 
-    let scene = Scene::new(
+    let mut scene = Scene::new(
         vec![pink_ball, blue_ball, green_ball, purple_ball],
         vec![],
         input_manager,
         main_camera,
     );
+    scene.asset_server = asset_server;
 
-    engine(scene);
+    engine(scene, EngineConfig::new());
 }
 
 fn camera_tick_update(scene: &mut Scene) {
@@ -118,6 +112,11 @@ fn camera_tick_update(scene: &mut Scene) {
         scene.main_camera.position.y -= movement_speed * scene.delta_time;
         scene.main_camera.rotation.y -= movement_speed * scene.delta_time;
     }
+
+    // Escape
+    if scene.input_manager.pressed_scancodes.contains(&1) {
+        scene.request_exit();
+    }
 }
 
 //https://learnopengl.com/Getting-started/Camera
@@ -3,11 +3,11 @@ use std::f32::consts::PI;
 use copper_engine::{
     blank_on_awake, blank_tick_update, engine,
     input::InputManager,
-    math::Vector3,
+    math::{Quaternion, Transform, Vector3},
     object,
-    object::{GameObject, Material},
-    structs::Scene,
-    Camera,
+    object::{Behaviour, FrameContext, GameObject, Material},
+    structs::{EntityId, Scene},
+    Camera, EngineConfig,
 };
 use object::model_loader::get_obj;
 
@@ -26,48 +26,45 @@ fn main() {
     let dragon = GameObject::new(
         "Dragon",
         get_obj("models/stanford_dragon_low.obj"),
-        [0.0, 0.0, 10.0],
-        [0.0, 0.0, 0.0],
-        [1.0, 1.0, 1.0],
-        Material {
-            ambient_color: [0.0, 0.2, 0.3],
-        },
-        dragon_tick_update,
+        Transform::from_translation(Vector3::new(0.0, 0.0, 10.0)),
+        Material::new([0.0, 0.2, 0.3]),
+        blank_tick_update,
         blank_on_awake,
-    );
+    )
+    .with_behaviour(Spin {
+        axis: Vector3::new(1.0, 1.0, 0.0),
+        speed: 100.0,
+    });
 
     let suzane = GameObject::new(
         "Suzane",
         get_obj("models/suzane.obj"),
-        [0.0, 0.0, 5.0],
-        [0.0, PI, PI],
-        [1.0, 1.0, 1.0],
-        Material {
-            ambient_color: [0.3, 0.2, 0.0],
-        },
-        suzane_tick_update,
+        Transform::new(
+            Vector3::new(0.0, 0.0, 5.0),
+            Quaternion::from_euler_separate(0.0, PI, 0.0),
+            Vector3::new(1.0, 1.0, 1.0),
+        ),
+        Material::new([0.3, 0.2, 0.0]),
+        blank_tick_update,
         blank_on_awake,
     );
 
     let scene: Scene = Scene::new(vec![dragon, suzane], vec![], input_manager, main_camera);
 
-    engine(scene);
+    engine(scene, EngineConfig::new());
 }
 
-fn dragon_tick_update(scene: &mut Scene) {
-    scene.game_objects[0].rotation[0] =
-        scene.game_objects[0].rotation[0] + 100.0 * scene.delta_time;
-    scene.game_objects[0].rotation[1] =
-        scene.game_objects[0].rotation[1] + 100.0 * scene.delta_time;
-
-    if scene.game_objects[0].rotation[0] > 2.0 * PI {
-        scene.game_objects[0].rotation[0] = 0.0;
-    }
-    if scene.game_objects[0].rotation[1] > 2.0 * PI {
-        scene.game_objects[0].rotation[1] = 0.0;
-    }
+struct Spin {
+    axis: Vector3,
+    speed: f32,
 }
 
-fn suzane_tick_update(scene: &mut Scene) {
-    // scene.game_objects[1].translation[2] = - (scene.time_since_start.sin() + 10.0);
+impl Behaviour for Spin {
+    fn on_update(&mut self, scene: &mut Scene, id: EntityId, context: &FrameContext) {
+        let spin = Quaternion::from_axis_angle(self.axis, self.speed * context.delta_time);
+
+        if let Some(transform) = scene.get_mut::<Transform>(id) {
+            transform.rotate(spin);
+        }
+    }
 }
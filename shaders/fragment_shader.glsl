@@ -2,14 +2,17 @@
 
 in vec3 v_normal;
 in vec3 v_position;
+in vec2 v_tex_coords;
 
 out vec4 color;
 
 uniform vec3 u_light;
 uniform vec3 u_ambient_color;
 uniform vec3 u_diffuse_color;
-
-const vec3 specular_color = vec3(1.0, 1.0, 1.0);
+uniform vec3 u_specular_color;
+uniform float u_shininess;
+uniform float u_dissolve;
+uniform sampler2D u_albedo;
 
 void main() {
   float diffuse = max(dot(normalize(v_normal), normalize(u_light)), 0.0);
@@ -18,9 +21,11 @@ void main() {
   vec3 half_direction = normalize(normalize(u_light) + camera_dir);
   
   float specular =
-      pow(max(dot(half_direction, normalize(v_normal)), 0.0), 16.0);
+      pow(max(dot(half_direction, normalize(v_normal)), 0.0), max(u_shininess, 1.0));
+
+  vec3 albedo = texture(u_albedo, v_tex_coords).rgb;
 
-  color = vec4(u_ambient_color + diffuse * u_diffuse_color +
-                   specular * specular_color,
-               1.0);
+  color = vec4(albedo * (u_ambient_color + diffuse * u_diffuse_color) +
+                   specular * u_specular_color,
+               u_dissolve);
 }
\ No newline at end of file
@@ -2,9 +2,11 @@
 
 in vec3 position;
 in vec3 normal;
+in vec2 tex_coords;
 
 out vec3 v_normal;
 out vec3 v_position;
+out vec2 v_tex_coords;
 
 uniform mat4 projection;
 uniform mat4 view;
@@ -13,6 +15,7 @@ uniform mat4 model_pos_mat;
 void main() {
   mat4 model_view = view * model_pos_mat;
   v_normal = transpose(inverse(mat3(model_view))) * normal;
+  v_tex_coords = tex_coords;
   gl_Position = projection * model_view * vec4(position, 1.0);
 
   v_position = gl_Position.xyz / gl_Position.w;
@@ -0,0 +1,142 @@
+//! Handles share one loaded asset, and the GPU resources uploaded for it, between everything using it.
+
+use std::{
+    cell::OnceCell,
+    fmt,
+    ops::Deref,
+    rc::{Rc, Weak},
+};
+
+/// Asset that can be shared through handles, like `Model`, `Texture`, `Shader` and `Sound`.
+pub trait Asset {
+    /// Resources the engine uploads to the GPU for the asset, like vertex buffers or a compiled program. Uploaded
+    /// once and shared by every handle of the asset.
+    type Gpu;
+}
+
+/// Asset together with its GPU resources, once uploaded.
+struct Shared<T: Asset> {
+    asset: T,
+    gpu: OnceCell<T::Gpu>,
+}
+
+/// Reference counted handle to an asset. Cloning a handle shares the asset instead of copying it, and the asset and
+/// its GPU resources are freed when the last handle is dropped. The asset can be read through the handle, but not
+/// changed.
+///
+/// Handles are returned by the `AssetServer`, which gives out the same handle when a path is loaded again, or created
+/// from an asset with `Handle::new()` or `.into()`.
+///
+/// # Examples
+/// ```
+/// use copper_engine::{assets::Handle, object::model_loader::Model};
+///
+/// let cube = Handle::new(Model::cube(1.0));
+/// let same_cube = cube.clone();
+///
+/// assert!(Handle::ptr_eq(&cube, &same_cube));
+/// assert_eq!(Handle::handle_count(&cube), 2);
+/// assert_eq!(same_cube.positions.len(), cube.positions.len());
+/// ```
+pub struct Handle<T: Asset> {
+    shared: Rc<Shared<T>>,
+}
+
+impl<T: Asset> Handle<T> {
+    /// creates the first handle of the asset. Handles created separately never share their asset, use the
+    /// `AssetServer` or clone the handle to share it.
+    pub fn new(asset: T) -> Self {
+        Self {
+            shared: Rc::new(Shared {
+                asset,
+                gpu: OnceCell::new(),
+            }),
+        }
+    }
+
+    /// Whether both handles point to the same asset.
+    pub fn ptr_eq(handle: &Self, other: &Self) -> bool {
+        Rc::ptr_eq(&handle.shared, &other.shared)
+    }
+
+    /// Number of handles pointing to the asset of the handle, including the handle itself.
+    pub fn handle_count(handle: &Self) -> usize {
+        Rc::strong_count(&handle.shared)
+    }
+
+    /// Whether the GPU resources of the asset have been uploaded.
+    pub fn is_uploaded(handle: &Self) -> bool {
+        handle.shared.gpu.get().is_some()
+    }
+
+    /// GPU resources of the asset, `None` until uploaded.
+    pub(crate) fn gpu(&self) -> Option<&T::Gpu> {
+        self.shared.gpu.get()
+    }
+
+    /// GPU resources of the asset, uploaded with `upload` unless an other handle of the asset already did.
+    pub(crate) fn upload<E>(
+        &self,
+        upload: impl FnOnce(&T) -> Result<T::Gpu, E>,
+    ) -> Result<&T::Gpu, E> {
+        if self.shared.gpu.get().is_none() {
+            let gpu = upload(&self.shared.asset)?;
+            let _ = self.shared.gpu.set(gpu);
+        }
+
+        Ok(self.shared.gpu.get().unwrap())
+    }
+
+    /// Weak reference to the asset, which does not keep it loaded.
+    pub(crate) fn downgrade(&self) -> WeakHandle<T> {
+        WeakHandle(Rc::downgrade(&self.shared))
+    }
+}
+
+impl<T: Asset> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Rc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T: Asset> Deref for Handle<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.shared.asset
+    }
+}
+
+impl<T: Asset> From<T> for Handle<T> {
+    fn from(asset: T) -> Self {
+        Self::new(asset)
+    }
+}
+
+impl<T: Asset + fmt::Debug> fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Handle").field(&self.shared.asset).finish()
+    }
+}
+
+/// Handle that does not keep its asset loaded, used by caches of loaded assets.
+pub(crate) struct WeakHandle<T: Asset>(Weak<Shared<T>>);
+
+impl<T: Asset> WeakHandle<T> {
+    /// creates weak handle that never points to an asset.
+    pub(crate) fn new() -> Self {
+        Self(Weak::new())
+    }
+
+    /// Handle to the asset, `None` if every handle of it has been dropped.
+    pub(crate) fn upgrade(&self) -> Option<Handle<T>> {
+        self.0.upgrade().map(|shared| Handle { shared })
+    }
+
+    /// Whether the asset is still loaded.
+    pub(crate) fn is_alive(&self) -> bool {
+        self.0.strong_count() > 0
+    }
+}
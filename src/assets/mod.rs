@@ -0,0 +1,7 @@
+pub mod handle;
+pub mod paths;
+pub mod server;
+
+pub use handle::{Asset, Handle};
+pub use paths::{asset_root, executable_dir, resolve, set_asset_root, ASSET_ROOT_ENV};
+pub use server::AssetServer;
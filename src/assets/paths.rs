@@ -0,0 +1,98 @@
+//! Asset paths are resolved against an asset root, so games work no matter which directory they are started from.
+//!
+//! Every loader of the engine, like `get_obj()`, `Texture::load()`, `AudioSourceLocal::new()` and the `AssetServer`,
+//! resolves its path with `resolve()`.
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+/// Environment variable overriding the asset root, for example to run a game with the assets of a source checkout.
+pub const ASSET_ROOT_ENV: &str = "COPPER_ASSET_ROOT";
+
+static ASSET_ROOT: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// sets the directory relative asset paths are resolved against, or removes it with `None`. A relative root is
+/// relative to the directory of the executable, see `asset_root()`. Also set from
+/// `EngineConfig::asset_root` by `EngineConfig::apply_asset_root()` and `try_engine()`. Only assets loaded
+/// afterwards use the new root, so it has to be set before the scene is built.
+pub fn set_asset_root(root: Option<PathBuf>) {
+    *ASSET_ROOT.write().unwrap() = root;
+}
+
+/// Explicitly chosen asset root: the `COPPER_ASSET_ROOT` environment variable if it is set, otherwise the root set
+/// with `set_asset_root()`. Relative roots are relative to the directory of the executable, not to the working
+/// directory, so `asset_root = "assets"` finds the assets next to the game however it is started.
+///
+/// # Examples
+/// ```
+/// use std::path::PathBuf;
+/// use copper_engine::assets::{asset_root, executable_dir, resolve, set_asset_root};
+///
+/// set_asset_root(Some(PathBuf::from("assets")));
+///
+/// let root = executable_dir().unwrap().join("assets");
+/// assert_eq!(asset_root(), Some(root.clone()));
+/// assert_eq!(resolve("models/ico.obj"), root.join("models/ico.obj"));
+/// ```
+pub fn asset_root() -> Option<PathBuf> {
+    let root = match env::var_os(ASSET_ROOT_ENV) {
+        Some(root) if !root.is_empty() => PathBuf::from(root),
+        _ => ASSET_ROOT.read().unwrap().clone()?,
+    };
+
+    if root.is_relative() {
+        if let Some(executable_dir) = executable_dir() {
+            return Some(executable_dir.join(root));
+        }
+    }
+
+    Some(root)
+}
+
+/// Directory of the running executable.
+pub fn executable_dir() -> Option<PathBuf> {
+    env::current_exe()
+        .ok()
+        .and_then(|executable| executable.parent().map(Path::to_path_buf))
+}
+
+/// Path of an asset. Absolute paths are kept. Relative paths are joined to
+/// 1. the explicit asset root, see `asset_root()`, if there is one,
+/// 2. the directory of the executable, if the asset exists there,
+/// 3. the current working directory otherwise.
+///
+/// # Examples
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use copper_engine::assets::{resolve, set_asset_root};
+///
+/// // Not next to the doc test executable, so relative to the working directory.
+/// assert_eq!(resolve("models/ico.obj"), Path::new("models/ico.obj"));
+///
+/// set_asset_root(Some(PathBuf::from("/opt/my_game/assets")));
+/// assert_eq!(resolve("models/ico.obj"), Path::new("/opt/my_game/assets/models/ico.obj"));
+/// ```
+pub fn resolve(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+
+    if let Some(root) = asset_root() {
+        return root.join(path);
+    }
+
+    if let Some(executable_dir) = executable_dir() {
+        let next_to_executable = executable_dir.join(path);
+
+        if next_to_executable.exists() {
+            return next_to_executable;
+        }
+    }
+
+    path.to_path_buf()
+}
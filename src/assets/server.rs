@@ -0,0 +1,201 @@
+//! Asset server loads every asset file once and hands out shared handles to it.
+
+use std::{
+    collections::HashMap,
+    fs,
+    hash::Hash,
+    path::{Path, PathBuf},
+};
+
+use soloud::SoloudError;
+
+use super::{handle::WeakHandle, resolve, Asset, Handle};
+use crate::audio::Sound;
+use crate::error::EngineError;
+use crate::object::{
+    gltf_loader::load_gltf_meshes_with,
+    model_loader::{load_obj_with, Model, ModelLoadError},
+    Shader, Texture,
+};
+
+/// Loads assets and remembers them by their path resolved with `assets::resolve()`. Loading a path again while a
+/// handle of it is alive returns the same handle instead of reading the file again, so game objects sharing a model,
+/// texture or shader also share its GPU resources. The server does not keep assets loaded by itself, an asset is
+/// freed when its last handle is dropped and read from the file again when it is loaded next.
+///
+/// Every scene has an asset server, `Scene::asset_server`, for loading assets after the scene is created.
+/// Usage of `::new()` is strongly recommended!
+///
+/// # Examples
+/// ```
+/// use copper_engine::assets::{AssetServer, Handle};
+///
+/// let mut asset_server = AssetServer::new();
+///
+/// let ball = asset_server.load_model("models/ico.obj").unwrap();
+/// let other_ball = asset_server.load_model("models/ico.obj").unwrap();
+/// assert!(Handle::ptr_eq(&ball, &other_ball));
+/// assert_eq!(asset_server.len(), 1);
+///
+/// drop(ball);
+/// drop(other_ball);
+/// assert!(asset_server.is_empty());
+/// ```
+pub struct AssetServer {
+    models: HashMap<PathBuf, WeakHandle<Model>>,
+    textures: HashMap<PathBuf, WeakHandle<Texture>>,
+    sounds: HashMap<PathBuf, WeakHandle<Sound>>,
+    shaders: HashMap<(PathBuf, PathBuf), WeakHandle<Shader>>,
+}
+
+impl AssetServer {
+    /// creates asset server without loaded assets.
+    pub fn new() -> Self {
+        Self {
+            models: HashMap::new(),
+            textures: HashMap::new(),
+            sounds: HashMap::new(),
+            shaders: HashMap::new(),
+        }
+    }
+
+    /// loads wavefront OBJ, glTF or GLB file, chosen by the extension of the path, or returns the handle of the
+    /// already loaded model. The textures of its materials are loaded like with `load_texture()`, so models and
+    /// materials using the same image file share one texture.
+    ///
+    /// A handle holds a single mesh: only the first mesh of the file is loaded, every other mesh is left out. Files
+    /// with several meshes are loaded with `model_loader::load_obj_meshes()`, `gltf_loader::load_gltf_meshes()` or,
+    /// keeping the node hierarchy of glTF files, `gltf_loader::load_gltf_objects()`.
+    ///
+    /// # Errors
+    /// Same as `model_loader::load_obj()` or `gltf_loader::load_gltf_meshes()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::assets::{AssetServer, Handle};
+    ///
+    /// let mut asset_server = AssetServer::new();
+    ///
+    /// // The lid of the crate uses textures/debug_map.png as albedo texture.
+    /// let lid = asset_server.load_model("models/crate.obj").unwrap();
+    /// let debug_map = asset_server.load_texture("textures/debug_map.png").unwrap();
+    /// let wood = lid.material.as_ref().unwrap().albedo_texture.as_ref().unwrap();
+    /// assert!(Handle::ptr_eq(wood, &debug_map));
+    ///
+    /// // glTF files with the texture in a file of its own share it as well.
+    /// let robot = asset_server.load_model("models/robot.gltf").unwrap();
+    /// let painted = robot.material.as_ref().unwrap().albedo_texture.as_ref().unwrap();
+    /// assert!(Handle::ptr_eq(painted, &asset_server.load_texture("models/robot_texture.png").unwrap()));
+    ///
+    /// // Two models and two textures.
+    /// assert_eq!(asset_server.len(), 4);
+    /// ```
+    pub fn load_model(&mut self, path: &str) -> Result<Handle<Model>, ModelLoadError> {
+        let resolved = resolve(path);
+        let is_gltf = resolved
+            .extension()
+            .is_some_and(|extension| extension == "gltf" || extension == "glb");
+
+        let textures = &mut self.textures;
+        let mut load_texture = |path: &Path| -> Result<Handle<Texture>, ModelLoadError> {
+            Ok(load_cached(textures, texture_key(path), || {
+                Texture::from_path(path)
+            })?)
+        };
+
+        load_cached(&mut self.models, resolved, || {
+            if is_gltf {
+                load_gltf_meshes_with(path, &mut load_texture)?
+                    .into_iter()
+                    .next()
+                    .ok_or(ModelLoadError::NoMeshes)
+            } else {
+                load_obj_with(path, &mut load_texture)
+            }
+        })
+    }
+
+    /// loads texture, or returns the handle of the already loaded texture.
+    ///
+    /// # Errors
+    /// Same as `Texture::load()`.
+    pub fn load_texture(&mut self, path: &str) -> Result<Handle<Texture>, image::ImageError> {
+        let resolved = resolve(path);
+
+        load_cached(&mut self.textures, texture_key(&resolved), || {
+            Texture::from_path(&resolved)
+        })
+    }
+
+    /// loads sound, or returns the handle of the already loaded sound.
+    ///
+    /// # Errors
+    /// Same as `Sound::load()`.
+    pub fn load_sound(&mut self, path: &str) -> Result<Handle<Sound>, SoloudError> {
+        load_cached(&mut self.sounds, resolve(path), || Sound::load(path))
+    }
+
+    /// loads shader from its vertex and fragment shader files, or returns the handle of the already loaded shader.
+    ///
+    /// # Errors
+    /// Same as `Shader::load()`.
+    pub fn load_shader(
+        &mut self,
+        vertex_path: &str,
+        fragment_path: &str,
+    ) -> Result<Handle<Shader>, EngineError> {
+        let key = (resolve(vertex_path), resolve(fragment_path));
+
+        load_cached(&mut self.shaders, key, || {
+            Shader::load(vertex_path, fragment_path)
+        })
+    }
+
+    /// Number of loaded assets that still have handles.
+    pub fn len(&self) -> usize {
+        count_alive(&self.models)
+            + count_alive(&self.textures)
+            + count_alive(&self.sounds)
+            + count_alive(&self.shaders)
+    }
+
+    /// Whether every asset loaded by the server has been freed.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for AssetServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the cached handle of `key` if it is still alive, otherwise loads the asset with `load` and caches it.
+/// Entries of freed assets are removed whenever an asset is loaded.
+fn load_cached<K: Eq + Hash, T: Asset, E>(
+    cache: &mut HashMap<K, WeakHandle<T>>,
+    key: K,
+    load: impl FnOnce() -> Result<T, E>,
+) -> Result<Handle<T>, E> {
+    if let Some(handle) = cache.get(&key).and_then(WeakHandle::upgrade) {
+        return Ok(handle);
+    }
+
+    cache.retain(|_, asset| asset.is_alive());
+
+    let handle = Handle::new(load()?);
+    cache.insert(key, handle.downgrade());
+
+    Ok(handle)
+}
+
+/// Key of a texture in the cache. Material files refer to textures relative to themselves, like
+/// `../textures/wood.png`, so the path is made canonical to match the same file loaded from elsewhere.
+fn texture_key(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn count_alive<K, T: Asset>(cache: &HashMap<K, WeakHandle<T>>) -> usize {
+    cache.values().filter(|asset| asset.is_alive()).count()
+}
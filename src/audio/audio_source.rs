@@ -1,5 +1,6 @@
-use soloud::{audio, AudioExt, LoadExt, Wav};
-use std::path::Path;
+use super::Sound;
+use crate::assets::Handle;
+use crate::math::Vector3;
 
 pub enum AudioSource {
     Local(AudioSourceLocal),
@@ -8,12 +9,12 @@ pub enum AudioSource {
 
 /// **Supported formats: wav, mp3, ogg, and flac.**
 /// # Fields
-/// - sound: Instance of `slound::audio::Wav`.
+/// - sound: Sound played by the source, can be shared with other sources.
 /// - volume: Volume of the sound where `1.0` is normal.
 /// - triggered: Bool indicating if the audio will be plaid this cycle.
 /// Usage of `::new()` is strongly recommended!
 pub struct AudioSourceGlobal {
-    pub sound: Wav,
+    pub sound: Handle<Sound>,
     pub volume: f32,
     pub triggered: bool,
 }
@@ -32,9 +33,13 @@ impl AudioSourceGlobal {
     /// let audio_source = AudioSourceGlobal::new("path/to/music.mp3", 1.0, true);
     /// ```
     pub fn new(path: &str, volume: f32, play_on_awake: bool) -> Self {
-        let mut sound = audio::Wav::default();
-        sound.load(&Path::new(path)).expect("Failed to load audio!");
+        let sound = Sound::load(path).expect("Failed to load audio!");
 
+        Self::from_sound(sound.into(), volume, play_on_awake)
+    }
+
+    /// creates source playing an already loaded sound, for example one loaded with `AssetServer::load_sound()`.
+    pub fn from_sound(sound: Handle<Sound>, volume: f32, play_on_awake: bool) -> Self {
         Self {
             sound,
             volume,
@@ -45,18 +50,18 @@ impl AudioSourceGlobal {
 
 /// **Supported formats: wav, mp3, ogg, and flac.**
 /// # Fields
-/// - sound: Instance of `slound::audio::Wav`.
+/// - sound: Sound played by the source, can be shared with other sources.
 /// - volume: Volume of the sound where `1.0` is normal.
 /// - triggered: Bool indicating if the audio will be plaid this cycle.
-/// - position: Set of \[x, y, z] coordinates indicating the translation of the sound form the world origin (0, 0, 0).
+/// - position: Translation of the sound from the world origin (0, 0, 0).
 /// - amplifier: Multiplies the distance to give clearer effects.
 /// 
 /// Usage of `::new()` is strongly recommended!
 pub struct AudioSourceLocal {
-    pub sound: Wav,
+    pub sound: Handle<Sound>,
     pub volume: f32,
     pub triggered: bool,
-    pub position: [f32; 3],
+    pub position: Vector3,
     pub amplifier: f32,
 }
 
@@ -77,17 +82,27 @@ impl AudioSourceLocal {
         path: &str,
         volume: f32,
         play_on_awake: bool,
-        position: [f32; 3],
+        position: impl Into<Vector3>,
         amplifier: f32,
     ) -> Self {
-        let mut sound = audio::Wav::default();
-        sound.load(&Path::new(path)).expect("Failed to load audio!");
+        let sound = Sound::load(path).expect("Failed to load audio!");
+
+        Self::from_sound(sound.into(), volume, play_on_awake, position, amplifier)
+    }
 
+    /// creates source playing an already loaded sound, for example one loaded with `AssetServer::load_sound()`.
+    pub fn from_sound(
+        sound: Handle<Sound>,
+        volume: f32,
+        play_on_awake: bool,
+        position: impl Into<Vector3>,
+        amplifier: f32,
+    ) -> Self {
         Self {
             sound,
             volume,
             triggered: play_on_awake,
-            position,
+            position: position.into(),
             amplifier,
         }
     }
@@ -1,3 +1,5 @@
 pub mod audio_source;
+pub mod sound;
 
-pub use audio_source::AudioSource;
\ No newline at end of file
+pub use audio_source::AudioSource;
+pub use sound::Sound;
@@ -0,0 +1,41 @@
+//! Sound is an audio file loaded into memory, shared by the audio sources playing it.
+
+use soloud::{audio::Wav, AudioExt, LoadExt, SoloudError};
+
+use crate::assets::{resolve, Asset};
+
+/// **Supported formats: wav, mp3, ogg, and flac.**
+/// # Fields
+/// - wav: Instance of `soloud::audio::Wav`.
+///
+/// Usually shared between audio sources through a `Handle<Sound>`. Usage of `::load()` is strongly recommended!
+pub struct Sound {
+    pub wav: Wav,
+}
+
+impl Sound {
+    /// loads sound file into memory. The path is resolved with `assets::resolve()`.
+    ///
+    /// # Errors
+    /// - i/o error
+    /// - Null value error in the read memory
+    /// - Internal soloud error
+    /// - Unknown error
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use copper_engine::audio::Sound;
+    ///
+    /// let sound = Sound::load("sounds/jump.wav").unwrap();
+    /// ```
+    pub fn load(path: &str) -> Result<Self, SoloudError> {
+        let mut wav = Wav::default();
+        wav.load(resolve(path))?;
+
+        Ok(Self { wav })
+    }
+}
+
+impl Asset for Sound {
+    type Gpu = ();
+}
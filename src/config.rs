@@ -0,0 +1,222 @@
+//! Engine config holds the window and rendering settings passed to `engine()`.
+
+use std::{
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::math::{UVector2, Vector4};
+
+/// How the window is shown.
+///
+/// # Variants
+/// - Windowed: Normal window with the size of the config.
+/// - Borderless: Borderless window covering the whole current monitor.
+/// - Fullscreen: Exclusive fullscreen using the video mode of the primary monitor closest to the size of the config.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowMode {
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+
+/// # Fields
+/// - title: Title of the window.
+/// - window_size: Initial width as x and height as y of the window in logical pixels. Written as `[width, height]`
+///   in config files.
+/// - resizable: Whether the user can resize the window.
+/// - window_mode: Windowed, borderless or exclusive fullscreen.
+/// - vsync: Whether presenting a frame waits for the vertical blank of the monitor.
+/// - multisampling: Number of samples per pixel for anti-aliasing, `0` disables multisampling. Should be a power of two.
+/// - depth_bits: Bits of the depth buffer.
+/// - fps_limit: Most frames drawn per second, `0` for no limit.
+/// - clear_color: r, g, b and a as x, y, z and w in range 0.0-1.0. Color of the background. Written as
+///   `[r, g, b, a]` in config files.
+/// - debug_ui: Whether the debug window is shown.
+/// - asset_root: Directory relative asset paths are resolved against. A relative root is relative to the directory of
+///   the executable. Applied by `try_engine()`, call `apply_asset_root()` to use it for the assets of the scene built
+///   before. See `assets::resolve()`.
+///
+/// Every field missing from a config file keeps its default value. Usage of `::new()` or `::load()` is strongly
+/// recommended!
+///
+/// # Examples
+/// ```
+/// use copper_engine::{math::{UVector2, Vector4}, EngineConfig, WindowMode};
+///
+/// let config = EngineConfig::from_toml_str(
+///     r#"
+///     title = "My game"
+///     window_size = [1280, 720]
+///     window_mode = "borderless"
+///     fps_limit = 144
+///     clear_color = [0.0, 0.0, 0.0, 1.0]
+///     "#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(config.title, "My game");
+/// assert_eq!(config.window_size, UVector2::new(1280, 720));
+/// assert_eq!(config.clear_color, Vector4::new(0.0, 0.0, 0.0, 1.0));
+/// assert_eq!(config.window_mode, WindowMode::Borderless);
+/// assert_eq!(config.fps_limit, 144);
+/// assert!(config.debug_ui);
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EngineConfig {
+    pub title: String,
+    pub window_size: UVector2,
+    pub resizable: bool,
+    pub window_mode: WindowMode,
+    pub vsync: bool,
+    pub multisampling: u16,
+    pub depth_bits: u8,
+    pub fps_limit: u32,
+    pub clear_color: Vector4,
+    pub debug_ui: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_root: Option<PathBuf>,
+}
+
+impl EngineConfig {
+    /// creates config for a resizable 800x600 window titled "copper engine" without vsync, multisampling or fps
+    /// limit and with the debug window shown. Assets are found next to the executable or in the working directory.
+    pub fn new() -> Self {
+        Self {
+            title: String::from("copper engine"),
+            window_size: UVector2::new(800, 600),
+            resizable: true,
+            window_mode: WindowMode::Windowed,
+            vsync: false,
+            multisampling: 0,
+            depth_bits: 24,
+            fps_limit: 0,
+            clear_color: Vector4::new(0.1, 0.2, 0.3, 1.0),
+            debug_ui: true,
+            asset_root: None,
+        }
+    }
+
+    /// parses config from TOML. Fields are named like the fields of the struct.
+    pub fn from_toml_str(toml: &str) -> Result<Self, ConfigError> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    /// Config as TOML, can be loaded again with `from_toml_str()`.
+    pub fn to_toml_string(&self) -> Result<String, ConfigError> {
+        Ok(toml::to_string(self)?)
+    }
+
+    /// loads config from a TOML settings file, so settings can be changed without recompiling. Only reads the file,
+    /// the asset root is applied by `apply_asset_root()` or `try_engine()`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use copper_engine::EngineConfig;
+    ///
+    /// let config = EngineConfig::load("settings.toml").unwrap_or_default();
+    /// ```
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        Self::from_toml_str(&fs::read_to_string(path)?)
+    }
+
+    /// makes `asset_root` the root relative asset paths are resolved against, see `assets::set_asset_root()`. Does
+    /// nothing if the config has no asset root. `try_engine()` calls it when the engine starts, call it before
+    /// building the scene so the assets of the scene are loaded from the root as well.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    /// use copper_engine::{assets::resolve, EngineConfig};
+    ///
+    /// let mut config = EngineConfig::new();
+    /// config.asset_root = Some(PathBuf::from("/opt/my_game/assets"));
+    /// config.apply_asset_root();
+    ///
+    /// // Models, textures and sounds of the scene are now loaded from the asset root.
+    /// assert_eq!(resolve("models/ico.obj"), Path::new("/opt/my_game/assets/models/ico.obj"));
+    /// ```
+    pub fn apply_asset_root(&self) {
+        if let Some(asset_root) = &self.asset_root {
+            crate::assets::set_asset_root(Some(asset_root.clone()));
+        }
+    }
+
+    /// saves config to a TOML settings file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ConfigError> {
+        Ok(fs::write(path, self.to_toml_string()?)?)
+    }
+
+    /// Time one frame should at least take to stay within `fps_limit`, `None` if there is no limit.
+    pub(crate) fn frame_duration(&self) -> Option<std::time::Duration> {
+        if self.fps_limit == 0 {
+            None
+        } else {
+            Some(std::time::Duration::from_secs_f64(
+                1.0 / self.fps_limit as f64,
+            ))
+        }
+    }
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Error returned when loading or saving an `EngineConfig`.
+///
+/// # Variants
+/// - Io: The settings file could not be read or written.
+/// - Parse: The settings file is not valid TOML or a field has the wrong type.
+/// - Serialize: The config could not be written as TOML.
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    Serialize(toml::ser::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(error) => write!(f, "Failed to access settings file: {}", error),
+            ConfigError::Parse(error) => write!(f, "Failed to parse settings: {}", error),
+            ConfigError::Serialize(error) => write!(f, "Failed to write settings: {}", error),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Io(error) => Some(error),
+            ConfigError::Parse(error) => Some(error),
+            ConfigError::Serialize(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(error: io::Error) -> Self {
+        ConfigError::Io(error)
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(error: toml::de::Error) -> Self {
+        ConfigError::Parse(error)
+    }
+}
+
+impl From<toml::ser::Error> for ConfigError {
+    fn from(error: toml::ser::Error) -> Self {
+        ConfigError::Serialize(error)
+    }
+}
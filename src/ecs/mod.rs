@@ -0,0 +1,64 @@
+//! Entity component system. Entities are `EntityId`s of a `Scene`, components are values of any `'static` type
+//! attached to them and stored in sparse sets, one per component type.
+//!
+//! Components are accessed with `Scene::get()` and `Scene::get_mut()` for single entities or with `Scene::query()`
+//! for every entity that has a set of components. `Scene::spawn()` takes a `Bundle`, a `GameObject` or a tuple of
+//! components.
+
+pub mod query;
+pub mod render;
+pub mod storage;
+pub mod system;
+
+pub use query::{Query, QueryIter, QueryParam};
+pub use render::{RenderContext, RenderSystem};
+pub use storage::SparseSet;
+pub use system::System;
+
+use crate::math::Matrix4x4;
+use crate::structs::{EntityId, Scene};
+
+/// Name of an entity, shows up in the debug menu and can be searched with `Scene::find()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Name(pub String);
+
+/// Parent and children of an entity. Every spawned entity has one, it is changed with `Scene::set_parent()` and read
+/// with `Scene::parent()` and `Scene::children()`. Private to the engine, so game code can not remove it and break
+/// the links between the entities.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Hierarchy {
+    pub(crate) parent: Option<EntityId>,
+    pub(crate) children: Vec<EntityId>,
+}
+
+/// Model matrix of an entity with a `Transform` in world space, updated every frame before drawing.
+#[derive(Clone, Copy, Debug)]
+pub struct WorldMatrix(pub Matrix4x4);
+
+/// Set of components spawned together with `Scene::spawn()`. Implemented for `GameObject` and for tuples of up to
+/// eight components.
+pub trait Bundle {
+    /// attaches the components to the freshly spawned entity.
+    fn insert_into(self, scene: &mut Scene, id: EntityId);
+}
+
+macro_rules! impl_bundle {
+    ($($component:ident),*) => {
+        impl<$($component: 'static),*> Bundle for ($($component,)*) {
+            #[allow(non_snake_case)]
+            fn insert_into(self, scene: &mut Scene, id: EntityId) {
+                let ($($component,)*) = self;
+                $(scene.insert(id, $component);)*
+            }
+        }
+    };
+}
+
+impl_bundle!(A);
+impl_bundle!(A, B);
+impl_bundle!(A, B, C);
+impl_bundle!(A, B, C, D);
+impl_bundle!(A, B, C, D, E);
+impl_bundle!(A, B, C, D, E, F);
+impl_bundle!(A, B, C, D, E, F, G);
+impl_bundle!(A, B, C, D, E, F, G, H);
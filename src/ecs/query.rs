@@ -0,0 +1,207 @@
+//! Queries iterate over every entity that has a set of components.
+
+use std::{any::TypeId, marker::PhantomData};
+
+use super::storage::{Components, SparseSet};
+use crate::structs::EntityId;
+
+/// Access to a single component type in a query, `&T` or `&mut T`.
+pub trait QueryParam {
+    type Item<'a>;
+    /// Pointer to the storage of the component.
+    type State: Copy;
+
+    fn component() -> TypeId;
+
+    /// Storage of the component, `None` if no entity ever had one.
+    fn state(components: &mut Components) -> Option<Self::State>;
+
+    /// Entities that have the component.
+    ///
+    /// # Safety
+    /// The storage behind `state` has to be alive.
+    unsafe fn ids(state: Self::State) -> Vec<EntityId>;
+
+    /// # Safety
+    /// The storage behind `state` has to outlive `'a` and no other reference to the component of the entity may
+    /// exist while the item is alive.
+    unsafe fn fetch<'a>(state: Self::State, id: EntityId) -> Option<Self::Item<'a>>;
+}
+
+impl<T: 'static> QueryParam for &T {
+    type Item<'a> = &'a T;
+    type State = *const SparseSet<T>;
+
+    fn component() -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    fn state(components: &mut Components) -> Option<Self::State> {
+        components
+            .storage::<T>()
+            .map(|storage| storage as *const SparseSet<T>)
+    }
+
+    unsafe fn ids(state: Self::State) -> Vec<EntityId> {
+        (*state).ids().to_vec()
+    }
+
+    unsafe fn fetch<'a>(state: Self::State, id: EntityId) -> Option<Self::Item<'a>> {
+        (*state).get(id)
+    }
+}
+
+impl<T: 'static> QueryParam for &mut T {
+    type Item<'a> = &'a mut T;
+    type State = *mut SparseSet<T>;
+
+    fn component() -> TypeId {
+        TypeId::of::<T>()
+    }
+
+    fn state(components: &mut Components) -> Option<Self::State> {
+        components
+            .storage_mut::<T>()
+            .map(|storage| storage as *mut SparseSet<T>)
+    }
+
+    unsafe fn ids(state: Self::State) -> Vec<EntityId> {
+        (*state).ids().to_vec()
+    }
+
+    unsafe fn fetch<'a>(state: Self::State, id: EntityId) -> Option<Self::Item<'a>> {
+        let dense = (*state).dense_index(id)?;
+
+        // `value_ptr()` does not borrow the components handed out before, so they stay valid.
+        Some(&mut *(*state).value_ptr(dense))
+    }
+}
+
+/// Set of components a query fetches for every entity, a component access or a tuple of up to six of them. Every
+/// component type may only appear once.
+pub trait Query {
+    type Item<'a>;
+    type State: Copy;
+
+    /// Component types accessed by the query, in order.
+    fn components() -> Vec<TypeId>;
+
+    fn state(components: &mut Components) -> Option<Self::State>;
+
+    /// Entities that may match, the entities with the first component of the query.
+    ///
+    /// # Safety
+    /// Same as `QueryParam::ids()`.
+    unsafe fn ids(state: Self::State) -> Vec<EntityId>;
+
+    /// # Safety
+    /// Same as `QueryParam::fetch()`.
+    unsafe fn fetch<'a>(state: Self::State, id: EntityId) -> Option<Self::Item<'a>>;
+}
+
+impl<P: QueryParam> Query for P {
+    type Item<'a> = P::Item<'a>;
+    type State = P::State;
+
+    fn components() -> Vec<TypeId> {
+        vec![P::component()]
+    }
+
+    fn state(components: &mut Components) -> Option<Self::State> {
+        P::state(components)
+    }
+
+    unsafe fn ids(state: Self::State) -> Vec<EntityId> {
+        P::ids(state)
+    }
+
+    unsafe fn fetch<'a>(state: Self::State, id: EntityId) -> Option<Self::Item<'a>> {
+        P::fetch(state, id)
+    }
+}
+
+macro_rules! impl_query {
+    ($first:ident $(, $param:ident)*) => {
+        impl<$first: QueryParam $(, $param: QueryParam)*> Query for ($first, $($param,)*) {
+            type Item<'a> = ($first::Item<'a>, $($param::Item<'a>,)*);
+            type State = ($first::State, $($param::State,)*);
+
+            fn components() -> Vec<TypeId> {
+                vec![$first::component() $(, $param::component())*]
+            }
+
+            fn state(components: &mut Components) -> Option<Self::State> {
+                Some(($first::state(components)?, $($param::state(components)?,)*))
+            }
+
+            unsafe fn ids(state: Self::State) -> Vec<EntityId> {
+                $first::ids(state.0)
+            }
+
+            #[allow(non_snake_case)]
+            unsafe fn fetch<'a>(state: Self::State, id: EntityId) -> Option<Self::Item<'a>> {
+                let ($first, $($param,)*) = state;
+
+                Some(($first::fetch($first, id)?, $($param::fetch($param, id)?,)*))
+            }
+        }
+    };
+}
+
+impl_query!(A);
+impl_query!(A, B);
+impl_query!(A, B, C);
+impl_query!(A, B, C, D);
+impl_query!(A, B, C, D, E);
+impl_query!(A, B, C, D, E, F);
+
+/// Iterator over the entities matching a query, created with `Scene::query()`.
+pub struct QueryIter<'a, Q: Query> {
+    state: Option<Q::State>,
+    ids: std::vec::IntoIter<EntityId>,
+    marker: PhantomData<&'a mut Components>,
+}
+
+impl<'a, Q: Query> QueryIter<'a, Q> {
+    /// # Panics
+    /// Panics if a component type appears more than once in the query.
+    pub(crate) fn new(components: &'a mut Components) -> Self {
+        let types = Q::components();
+        for (i, component) in types.iter().enumerate() {
+            assert!(
+                !types[..i].contains(component),
+                "Query accesses the same component type more than once"
+            );
+        }
+
+        let state = Q::state(components);
+        // SAFETY: the storages were just taken from `components`.
+        let ids = state
+            .map(|state| unsafe { Q::ids(state) })
+            .unwrap_or_default();
+
+        Self {
+            state,
+            ids: ids.into_iter(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, Q: Query> Iterator for QueryIter<'a, Q> {
+    type Item = (EntityId, Q::Item<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let state = self.state?;
+
+        for id in self.ids.by_ref() {
+            // SAFETY: the components are borrowed mutably for `'a`, the component types of the query are distinct
+            // and every entity is visited once, so no component is handed out twice.
+            if let Some(item) = unsafe { Q::fetch(state, id) } {
+                return Some((id, item));
+            }
+        }
+
+        None
+    }
+}
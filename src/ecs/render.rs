@@ -0,0 +1,86 @@
+//! Render systems draw the scene into the frame of the window, after every other system of the frame has run.
+
+use glium::{texture::SrgbTexture2d, Display, Frame};
+
+use crate::error::EngineError;
+use crate::math::Matrix4x4;
+use crate::object::FrameContext;
+use crate::structs::Scene;
+
+/// What a render system draws with, given to `RenderSystem::render()`.
+///
+/// # Fields
+/// - display: Window and OpenGL context, for uploading buffers, textures and programs.
+/// - target: Frame of the window being drawn. Already cleared to the clear color of the config.
+/// - projection: Perspective matrix of the main camera for the size of the frame.
+/// - view: View matrix of the main camera.
+/// - white_texture: Texture of one white pixel, used in place of missing albedo textures.
+/// - frame: Timing of the frame, same as the systems of the frame got.
+pub struct RenderContext<'a> {
+    pub display: &'a Display,
+    pub target: &'a mut Frame,
+    pub projection: Matrix4x4,
+    pub view: Matrix4x4,
+    pub white_texture: &'a SrgbTexture2d,
+    pub frame: FrameContext,
+}
+
+/// System drawing into the frame. Render systems are added with `Scene::add_render_system()` and run in the order
+/// they were added, once every drawn frame: after `on_late_update`, after spawned entities are awake and despawned
+/// ones removed and after the world matrices are updated from the final transforms. The debug window is drawn on
+/// top of them.
+///
+/// Every scene starts with a `MeshRendererSystem`, which draws every entity with `Transform`, `MeshRenderer` and
+/// `Material`. Render systems do not run in `engine_headless()`, which has no window.
+///
+/// Closures `FnMut(&mut Scene, &mut RenderContext) -> Result<(), EngineError>` are render systems.
+///
+/// # Examples
+/// ```
+/// use copper_engine::{ecs::{RenderContext, RenderSystem}, structs::Scene, EngineError};
+/// use glium::Surface;
+///
+/// /// Draws nothing, but counts the frames it is given.
+/// struct FrameCounter(u64);
+///
+/// impl RenderSystem for FrameCounter {
+///     fn render(&mut self, _scene: &mut Scene, context: &mut RenderContext) -> Result<(), EngineError> {
+///         let (width, height) = context.target.get_dimensions();
+///         assert!(width > 0 && height > 0);
+///         self.0 += 1;
+///
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait RenderSystem {
+    fn render(&mut self, scene: &mut Scene, context: &mut RenderContext)
+        -> Result<(), EngineError>;
+}
+
+impl<F: FnMut(&mut Scene, &mut RenderContext) -> Result<(), EngineError>> RenderSystem for F {
+    fn render(
+        &mut self,
+        scene: &mut Scene,
+        context: &mut RenderContext,
+    ) -> Result<(), EngineError> {
+        self(scene, context)
+    }
+}
+
+/// Runs every render system of the scene in order, until one of them fails. Render systems added during the run are
+/// first run in the next frame.
+pub(crate) fn run_render_systems(
+    scene: &mut Scene,
+    context: &mut RenderContext,
+) -> Result<(), EngineError> {
+    let mut systems = std::mem::take(&mut scene.render_systems);
+    let result = systems
+        .iter_mut()
+        .try_for_each(|system| system.render(scene, context));
+
+    systems.append(&mut scene.render_systems);
+    scene.render_systems = systems;
+
+    result
+}
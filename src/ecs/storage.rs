@@ -0,0 +1,168 @@
+//! Sparse set storage of components.
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
+
+use crate::structs::EntityId;
+
+/// Components of one type, packed densely and indexed by the slot of their entity.
+pub struct SparseSet<T> {
+    sparse: Vec<Option<usize>>,
+    ids: Vec<EntityId>,
+    values: Vec<T>,
+}
+
+impl<T> SparseSet<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            sparse: vec![],
+            ids: vec![],
+            values: vec![],
+        }
+    }
+
+    /// Position of the component of the entity in `values`.
+    pub(crate) fn dense_index(&self, id: EntityId) -> Option<usize> {
+        let dense = (*self.sparse.get(id.index as usize)?)?;
+
+        if self.ids[dense] == id {
+            Some(dense)
+        } else {
+            None
+        }
+    }
+
+    /// Adds component to the entity and returns the component it replaced.
+    pub(crate) fn insert(&mut self, id: EntityId, value: T) -> Option<T> {
+        let slot = id.index as usize;
+        if slot >= self.sparse.len() {
+            self.sparse.resize(slot + 1, None);
+        }
+
+        match self.sparse[slot] {
+            Some(dense) if self.ids[dense] == id => {
+                Some(std::mem::replace(&mut self.values[dense], value))
+            }
+            Some(dense) => {
+                self.ids[dense] = id;
+                self.values[dense] = value;
+                None
+            }
+            None => {
+                self.sparse[slot] = Some(self.values.len());
+                self.ids.push(id);
+                self.values.push(value);
+                None
+            }
+        }
+    }
+
+    pub(crate) fn remove(&mut self, id: EntityId) -> Option<T> {
+        let dense = self.dense_index(id)?;
+
+        self.sparse[id.index as usize] = None;
+        self.ids.swap_remove(dense);
+        let value = self.values.swap_remove(dense);
+
+        if let Some(moved) = self.ids.get(dense) {
+            self.sparse[moved.index as usize] = Some(dense);
+        }
+
+        Some(value)
+    }
+
+    pub fn get(&self, id: EntityId) -> Option<&T> {
+        self.dense_index(id).map(|dense| &self.values[dense])
+    }
+
+    pub fn get_mut(&mut self, id: EntityId) -> Option<&mut T> {
+        self.dense_index(id).map(|dense| &mut self.values[dense])
+    }
+
+    /// Entities that have the component.
+    pub fn ids(&self) -> &[EntityId] {
+        &self.ids
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Pointer to the component at `dense`. Does not create a reference to the other components, so pointers to
+    /// different components can be turned into mutable references at the same time.
+    pub(crate) fn value_ptr(&mut self, dense: usize) -> *mut T {
+        assert!(dense < self.values.len());
+
+        // SAFETY: `dense` is in bounds of the allocation.
+        unsafe { self.values.as_mut_ptr().add(dense) }
+    }
+}
+
+/// Sparse set with its component type erased.
+pub(crate) trait AnyStorage {
+    fn remove_entity(&mut self, id: EntityId);
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: 'static> AnyStorage for SparseSet<T> {
+    fn remove_entity(&mut self, id: EntityId) {
+        self.remove(id);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Storages of every component type of a scene. Only accessible through `Scene`.
+pub struct Components {
+    storages: HashMap<TypeId, Box<dyn AnyStorage>>,
+}
+
+impl Components {
+    pub(crate) fn new() -> Self {
+        Self {
+            storages: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn storage<T: 'static>(&self) -> Option<&SparseSet<T>> {
+        self.storages
+            .get(&TypeId::of::<T>())
+            .and_then(|storage| storage.as_any().downcast_ref())
+    }
+
+    pub(crate) fn storage_mut<T: 'static>(&mut self) -> Option<&mut SparseSet<T>> {
+        self.storages
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|storage| storage.as_any_mut().downcast_mut())
+    }
+
+    /// Storage of the component type, created if it does not exist yet.
+    pub(crate) fn storage_or_insert<T: 'static>(&mut self) -> &mut SparseSet<T> {
+        self.storages
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(SparseSet::<T>::new()))
+            .as_any_mut()
+            .downcast_mut()
+            .unwrap()
+    }
+
+    /// Removes every component of the entity.
+    pub(crate) fn remove_entity(&mut self, id: EntityId) {
+        for storage in self.storages.values_mut() {
+            storage.remove_entity(id);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Systems are functions run over the whole scene every frame.
+
+use crate::object::FrameContext;
+use crate::structs::Scene;
+
+/// Logic run once every frame, usually over the results of `Scene::query()`. Systems are added with
+/// `Scene::add_system()`, or with `Scene::add_fixed_system()` to run every fixed step, and run in the order they
+/// were added.
+///
+/// Closures `FnMut(&mut Scene, &FrameContext)` are systems.
+///
+/// Every frame runs the fixed steps first, then the update callbacks of the camera and the entities, then the systems
+/// and then `on_late_update`. Drawing happens after all of these, at the world matrices updated from the final
+/// transforms, in `RenderSystem`s, which also get the frame of the window.
+///
+/// # Examples
+/// ```
+/// use copper_engine::{ecs::System, math::Transform, object::FrameContext, structs::Scene};
+///
+/// struct Gravity(f32);
+///
+/// impl System for Gravity {
+///     fn run(&mut self, scene: &mut Scene, context: &FrameContext) {
+///         for (_, transform) in scene.query::<&mut Transform>() {
+///             transform.translation.y -= self.0 * context.delta_time;
+///         }
+///     }
+/// }
+/// ```
+pub trait System {
+    fn run(&mut self, scene: &mut Scene, context: &FrameContext);
+}
+
+impl<F: FnMut(&mut Scene, &FrameContext)> System for F {
+    fn run(&mut self, scene: &mut Scene, context: &FrameContext) {
+        self(scene, context)
+    }
+}
+
+/// Runs every system of the scene in order. Systems added during the run are first run in the next frame.
+pub(crate) fn run_systems(scene: &mut Scene, context: &FrameContext) {
+    run(scene, context, |scene| &mut scene.systems);
+}
+
+/// Runs every fixed step system of the scene in order. Systems added during the run are first run in the next step.
+pub(crate) fn run_fixed_systems(scene: &mut Scene, context: &FrameContext) {
+    run(scene, context, |scene| &mut scene.fixed_systems);
+}
+
+fn run(
+    scene: &mut Scene,
+    context: &FrameContext,
+    systems_of: fn(&mut Scene) -> &mut Vec<Box<dyn System>>,
+) {
+    let mut systems = std::mem::take(systems_of(scene));
+
+    for system in &mut systems {
+        system.run(scene, context);
+    }
+
+    systems.append(systems_of(scene));
+    *systems_of(scene) = systems;
+}
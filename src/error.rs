@@ -0,0 +1,126 @@
+//! Errors that can stop the engine from starting or running.
+
+use std::{error::Error, fmt, io};
+
+use glium::{
+    backend::glutin::DisplayCreationError, index, texture::TextureCreationError, vertex, DrawError,
+    ProgramCreationError, SwapBuffersError,
+};
+use soloud::SoloudError;
+
+/// Error returned by `try_engine()`, render systems and `Shader::load()`.
+///
+/// # Variants
+/// - WindowCreation: The window or its OpenGL context could not be created.
+/// - AudioDevice: The audio device could not be opened.
+/// - ShaderIo: A shader source file could not be read. Contains the resolved path of the file.
+/// - ShaderCompile: A shader failed to compile or link. Contains the GLSL log.
+/// - VertexBufferCreation: A vertex buffer of a game object could not be created.
+/// - IndexBufferCreation: An index buffer of a game object could not be created.
+/// - TextureCreation: A texture of a game object could not be uploaded.
+/// - Draw: Drawing a game object failed.
+/// - SwapBuffers: The finished frame could not be presented.
+#[derive(Debug)]
+pub enum EngineError {
+    WindowCreation(DisplayCreationError),
+    AudioDevice(SoloudError),
+    ShaderIo { path: String, error: io::Error },
+    ShaderCompile(String),
+    VertexBufferCreation(vertex::BufferCreationError),
+    IndexBufferCreation(index::BufferCreationError),
+    TextureCreation(TextureCreationError),
+    Draw(DrawError),
+    SwapBuffers(SwapBuffersError),
+}
+
+impl fmt::Display for EngineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EngineError::WindowCreation(error) => write!(f, "Failed to create window: {}", error),
+            EngineError::AudioDevice(error) => write!(f, "Failed to open audio device: {}", error),
+            EngineError::ShaderIo { path, error } => {
+                write!(f, "Failed to read shader {}: {}", path, error)
+            }
+            EngineError::ShaderCompile(log) => write!(f, "Failed to compile shader:\n{}", log),
+            EngineError::VertexBufferCreation(error) => {
+                write!(f, "Failed to create vertex buffer: {}", error)
+            }
+            EngineError::IndexBufferCreation(error) => {
+                write!(f, "Failed to create index buffer: {}", error)
+            }
+            EngineError::TextureCreation(error) => {
+                write!(f, "Failed to create texture: {}", error)
+            }
+            EngineError::Draw(error) => write!(f, "Failed to draw object: {}", error),
+            EngineError::SwapBuffers(error) => write!(f, "Failed to present frame: {}", error),
+        }
+    }
+}
+
+impl Error for EngineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EngineError::WindowCreation(error) => Some(error),
+            EngineError::AudioDevice(error) => Some(error),
+            EngineError::ShaderIo { error, .. } => Some(error),
+            EngineError::ShaderCompile(_) => None,
+            EngineError::VertexBufferCreation(error) => Some(error),
+            EngineError::IndexBufferCreation(error) => Some(error),
+            EngineError::TextureCreation(error) => Some(error),
+            EngineError::Draw(error) => Some(error),
+            EngineError::SwapBuffers(error) => Some(error),
+        }
+    }
+}
+
+impl From<DisplayCreationError> for EngineError {
+    fn from(error: DisplayCreationError) -> Self {
+        EngineError::WindowCreation(error)
+    }
+}
+
+impl From<SoloudError> for EngineError {
+    fn from(error: SoloudError) -> Self {
+        EngineError::AudioDevice(error)
+    }
+}
+
+impl From<ProgramCreationError> for EngineError {
+    fn from(error: ProgramCreationError) -> Self {
+        match error {
+            ProgramCreationError::CompilationError(log, _) => EngineError::ShaderCompile(log),
+            ProgramCreationError::LinkingError(log) => EngineError::ShaderCompile(log),
+            error => EngineError::ShaderCompile(error.to_string()),
+        }
+    }
+}
+
+impl From<vertex::BufferCreationError> for EngineError {
+    fn from(error: vertex::BufferCreationError) -> Self {
+        EngineError::VertexBufferCreation(error)
+    }
+}
+
+impl From<index::BufferCreationError> for EngineError {
+    fn from(error: index::BufferCreationError) -> Self {
+        EngineError::IndexBufferCreation(error)
+    }
+}
+
+impl From<TextureCreationError> for EngineError {
+    fn from(error: TextureCreationError) -> Self {
+        EngineError::TextureCreation(error)
+    }
+}
+
+impl From<DrawError> for EngineError {
+    fn from(error: DrawError) -> Self {
+        EngineError::Draw(error)
+    }
+}
+
+impl From<SwapBuffersError> for EngineError {
+    fn from(error: SwapBuffersError) -> Self {
+        EngineError::SwapBuffers(error)
+    }
+}
@@ -3,15 +3,17 @@
 use std::collections::HashSet;
 use glium::glutin::event;
 
+use crate::math::Vector2;
+
 /// # Fields
 /// - pressed_scancodes: Hash set of u32 numbers indicating key-codes.
-/// - mouse_position: position of the mouse inside of the window. (0, 0) is in the top left corner.
+/// - mouse_position: position of the mouse inside of the window in physical pixels. (0, 0) is in the top left corner.
 /// - modifiers: Contains info if shift, alt, ctrl, or logo is pressed.
 /// 
 /// Usage of `::new()` is strongly recommended!
 pub struct InputManager {
     pub pressed_scancodes: HashSet<u32>,
-    pub mouse_position: [f64; 2],
+    pub mouse_position: Vector2,
     pub modifiers: event::ModifiersState,
 }
 
@@ -25,7 +27,7 @@ impl InputManager {
     pub fn new() -> Self {
         Self {
             pressed_scancodes: HashSet::new(),
-            mouse_position: [0.0, 0.0],
+            mouse_position: Vector2::new(0.0, 0.0),
             modifiers: event::ModifiersState::default(),
         }
         
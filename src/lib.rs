@@ -3,24 +3,44 @@ extern crate glium;
 extern crate image;
 
 use audio::AudioSource;
+use ecs::{
+    render::run_render_systems,
+    system::{run_fixed_systems, run_systems},
+    Name, RenderContext,
+};
 use egui::vec2;
 use glium::{
     glutin::{
         self,
+        dpi::LogicalSize,
         event::{self, Event, WindowEvent},
         event_loop::{self, EventLoop},
-        window::WindowBuilder,
+        monitor::MonitorHandle,
+        platform::run_return::EventLoopExtRunReturn,
+        window::{CursorGrabMode, Fullscreen, Window, WindowBuilder},
     },
     Display, Surface,
 };
-use math::{Matrix4x4, Vector3};
-use soloud::{AudioExt, Soloud};
-use std::{f32::consts::PI, fs};
+use math::{Matrix4x4, Transform, UVector2, Vector2, Vector3};
+use object::{
+    behaviour::{call_behaviours, destroy_despawned},
+    mesh_renderer::upload_mesh_renderers,
+    texture::upload_texture,
+    FrameContext, Hooks, MeshRenderer, Texture,
+};
+use soloud::Soloud;
+use std::f32::consts::PI;
 use structs::scene::Scene;
 
+pub use config::{EngineConfig, WindowMode};
+pub use error::EngineError;
 pub use structs::*;
 
+pub mod assets;
 pub mod audio;
+pub mod config;
+pub mod ecs;
+pub mod error;
 pub mod input;
 pub mod math;
 pub mod object;
@@ -30,76 +50,426 @@ pub mod structs;
 pub fn blank_tick_update(_scene: &mut Scene) {}
 /// Blank template for on awake. Does not do anything, but fulfills the type requirements.
 pub fn blank_on_awake(_scene: &mut Scene) {}
+/// Blank template for on exit. Does not do anything, but fulfills the type requirements.
+pub fn blank_on_exit(_scene: &mut Scene) {}
+
+/// Simulated time between two frames in `engine_headless()`, in seconds.
+pub const HEADLESS_DELTA_TIME: f32 = 1.0 / 60.0;
+
+/// Source of the vertex shader of `Shader::standard()`, built into the engine.
+pub const VERTEX_SHADER_SRC: &str = include_str!("../shaders/vertex_shader.glsl");
+/// Source of the fragment shader of `Shader::standard()`, built into the engine.
+pub const FRAGMENT_SHADER_SRC: &str = include_str!("../shaders/fragment_shader.glsl");
+
+/// Timing of the current frame for behaviours.
+fn frame_context(scene: &Scene, frame: u64) -> FrameContext {
+    FrameContext {
+        delta_time: scene.delta_time,
+        time_since_start: scene.time_since_start,
+        frame,
+    }
+}
 
-/// Main loop of the engine.
-pub fn engine(mut scene: Scene) {
-    let event_loop = EventLoop::new();
-    let wb = WindowBuilder::new().with_title("copper engine");
-    let cb = glutin::ContextBuilder::new().with_depth_buffer(24);
-    let display = Display::new(wb, cb, &event_loop).expect("Failed to init display");
+/// Calls `on_awake` of every entity spawned since the last call, including entities spawned by these calls.
+fn awake_spawned(scene: &mut Scene, context: &FrameContext) {
+    while !scene.unawakened.is_empty() {
+        for id in std::mem::take(&mut scene.unawakened) {
+            if let Some(&hooks) = scene.get::<Hooks>(id) {
+                (hooks.on_awake)(scene);
+            }
 
-    let mut egui_glium = egui_glium::EguiGlium::new(&display, &event_loop);
+            call_behaviours(scene, id, context, |behaviour, scene, id, context| {
+                behaviour.on_awake(scene, id, context)
+            });
+        }
+    }
+}
+
+/// Calls `on_awake` of every entity and then of the main camera.
+fn awake(scene: &mut Scene, context: &FrameContext) {
+    awake_spawned(scene, context);
+
+    (scene.main_camera.on_awake)(scene);
+}
+
+/// Runs the fixed steps due since the last frame, at most `Scene::max_fixed_steps`. Every step calls
+/// `fixed_update_func` and `on_fixed_update` of every entity and then the fixed systems of the scene. Updates
+/// `Scene::fixed_alpha` afterwards.
+fn fixed_update(scene: &mut Scene, context: &FrameContext) {
+    if scene.fixed_delta_time <= 0.0 {
+        scene.fixed_alpha = 0.0;
+        return;
+    }
+
+    let context = FrameContext {
+        delta_time: scene.fixed_delta_time,
+        ..*context
+    };
+
+    scene.fixed_accumulator += scene.delta_time;
+
+    let mut steps = 0;
+    while scene.fixed_accumulator >= scene.fixed_delta_time {
+        if steps == scene.max_fixed_steps {
+            // Dropping the time that could not be caught up avoids the spiral of death.
+            scene.fixed_accumulator %= scene.fixed_delta_time;
+            break;
+        }
+
+        let ids: Vec<EntityId> = scene.entities().collect();
+        for id in ids {
+            if let Some(&hooks) = scene.get::<Hooks>(id) {
+                (hooks.fixed_update_func)(scene);
+            }
+
+            call_behaviours(scene, id, &context, |behaviour, scene, id, context| {
+                behaviour.on_fixed_update(scene, id, context)
+            });
+        }
+        run_fixed_systems(scene, &context);
+
+        scene.fixed_accumulator -= scene.fixed_delta_time;
+        steps += 1;
+    }
+
+    scene.fixed_alpha = scene.fixed_accumulator / scene.fixed_delta_time;
+}
+
+/// Runs the update callbacks of one frame: the fixed steps of `fixed_update()`, `tick_update_func` of the main
+/// camera, `tick_update_func` and `on_update` of every entity, the systems of the scene in the order they were added
+/// and finally `on_late_update` of every behaviour. Entities spawned during the update are awakened afterwards and
+/// first updated in the next frame, behaviours of despawned entities are destroyed.
+fn tick_update(scene: &mut Scene, context: &FrameContext) {
+    fixed_update(scene, context);
+
+    let ids: Vec<EntityId> = scene.entities().collect();
+
+    (scene.main_camera.tick_update_func)(scene);
+
+    for &id in &ids {
+        if let Some(&hooks) = scene.get::<Hooks>(id) {
+            (hooks.tick_update_func)(scene);
+        }
+
+        call_behaviours(scene, id, context, |behaviour, scene, id, context| {
+            behaviour.on_update(scene, id, context)
+        });
+    }
+
+    run_systems(scene, context);
+
+    for &id in &ids {
+        call_behaviours(scene, id, context, |behaviour, scene, id, context| {
+            behaviour.on_late_update(scene, id, context)
+        });
+    }
+
+    awake_spawned(scene, context);
+    destroy_despawned(scene, context);
+}
+
+/// Runs the scene loop for `frames` frames without a window, GPU or audio device. Stops early after a frame in which
+/// `Scene::request_exit()` was called. `Scene::on_exit` is called at the end.
+///
+/// Calls the same hooks and behaviours as `engine()`, but advances `delta_time` and
+/// `time_since_start` on a simulated clock of `HEADLESS_DELTA_TIME` seconds per frame. Models are not uploaded to
+/// the GPU, triggered audio sources are not played and window requests are ignored, so the scene can be inspected
+/// afterwards in tests.
+///
+/// # Examples
+/// ```
+/// use copper_engine::{blank_on_awake, engine_headless, input::InputManager, math::Vector3, structs::Scene, Camera};
+///
+/// fn move_camera(scene: &mut Scene) {
+///     scene.main_camera.position.x += scene.delta_time;
+/// }
+///
+/// let camera = Camera::new(
+///     0.1,
+///     100.0,
+///     1.0,
+///     Vector3::new(0.0, 0.0, 0.0),
+///     Vector3::new(0.0, 0.0, 1.0),
+///     move_camera,
+///     blank_on_awake,
+/// );
+/// let mut scene = Scene::new(vec![], vec![], InputManager::new(), camera);
+///
+/// engine_headless(&mut scene, 60);
+///
+/// assert!((scene.main_camera.position.x - 1.0).abs() < 1e-4);
+/// assert!((scene.time_since_start - 1.0).abs() < 1e-4);
+/// ```
+pub fn engine_headless(scene: &mut Scene, frames: u32) {
+    engine_headless_timed(scene, (0..frames).map(|_| HEADLESS_DELTA_TIME));
+}
+
+/// Same as `engine_headless()`, but runs one frame for every frame time in seconds of `frame_times`, like the
+/// measured frame times of a real display. Can be used to check that the fixed steps keep the same rate no matter
+/// how fast or uneven the frames are.
+///
+/// # Examples
+/// ```
+/// use copper_engine::{
+///     blank_on_awake, blank_tick_update, engine_headless_timed, input::InputManager, math::Vector3,
+///     object::FrameContext, structs::Scene, Camera,
+/// };
+///
+/// fn fixed_steps(frame_times: Vec<f32>) -> u32 {
+///     let camera = Camera::new(
+///         0.1,
+///         100.0,
+///         1.0,
+///         Vector3::new(0.0, 0.0, 0.0),
+///         Vector3::new(0.0, 0.0, 1.0),
+///         blank_tick_update,
+///         blank_on_awake,
+///     );
+///     let mut scene = Scene::new(vec![], vec![], InputManager::new(), camera);
+///     scene.fixed_delta_time = 1.0 / 50.0;
+///     scene.add_fixed_system(|scene: &mut Scene, _context: &FrameContext| {
+///         scene.main_camera.position.x += 1.0;
+///     });
+///
+///     engine_headless_timed(&mut scene, frame_times);
+///     scene.main_camera.position.x as u32
+/// }
+///
+/// // Two seconds on a 60 Hz display, a 144 Hz display and with uneven frames.
+/// let uneven = [0.004, 0.031, 0.017, 0.012, 0.036].repeat(20);
+///
+/// assert!(fixed_steps(vec![1.0 / 60.0; 120]).abs_diff(100) <= 1);
+/// assert!(fixed_steps(vec![1.0 / 144.0; 288]).abs_diff(100) <= 1);
+/// assert!(fixed_steps(uneven).abs_diff(100) <= 1);
+/// ```
+pub fn engine_headless_timed(scene: &mut Scene, frame_times: impl IntoIterator<Item = f32>) {
+    let context = frame_context(scene, 0);
+    awake(scene, &context);
+
+    scene.time_since_start = 0.0;
+
+    for (frame, frame_time) in frame_times.into_iter().enumerate() {
+        scene.delta_time = frame_time;
+        scene.time_since_start += frame_time;
+
+        let context = frame_context(scene, frame as u64);
+        tick_update(scene, &context);
+        scene.update_world_matrices();
+
+        if scene.exit_requested() {
+            break;
+        }
+    }
+
+    (scene.on_exit)(scene);
+}
+
+/// Shows the entity and its children as nested collapsing headers in the debug window.
+fn entity_tree_ui(ui: &mut egui::Ui, scene: &Scene, id: EntityId) {
+    let name = match scene.get::<Name>(id) {
+        Some(name) => name.0.clone(),
+        None => format!("Entity {}", id),
+    };
+
+    egui::CollapsingHeader::new(name)
+        .id_source(id)
+        .show(ui, |ui| {
+            if let Some(transform) = scene.get::<Transform>(id) {
+                ui.label(format!(
+                    "Translation: {:.3?}",
+                    transform.translation.to_array()
+                ));
+                ui.label(format!(
+                    "Rotation: {:.3?}",
+                    [
+                        transform.rotation.x,
+                        transform.rotation.y,
+                        transform.rotation.z,
+                        transform.rotation.w
+                    ]
+                ));
+                ui.label(format!("Scale: {:.3?}", transform.scale.to_array()));
+            }
+
+            if let Some(renderer) = scene.get::<MeshRenderer>(id) {
+                ui.separator();
+
+                ui.label(format!("Vertices: {}", renderer.model.positions.len()));
+                ui.label(format!("Indices: {}", renderer.model.indices.len()));
+                ui.label(format!("Normals: {}", renderer.model.normals.len()));
+            }
 
-    let sl = Soloud::default().unwrap();
+            let children = scene.children(id);
+            if !children.is_empty() {
+                ui.separator();
 
-    let vertex_shader_src = fs::read_to_string("shaders/vertex_shader.glsl").expect("");
-    let vertex_shader_src = vertex_shader_src.as_str();
+                for &child in children {
+                    entity_tree_ui(ui, scene, child);
+                }
+            }
+        });
+}
 
-    let fragment_shader_src = fs::read_to_string("shaders/fragment_shader.glsl").expect("");
-    let fragment_shader_src = fragment_shader_src.as_str();
+/// Fullscreen setting of the window mode. Exclusive fullscreen uses the video mode of `monitor` closest to `size`.
+fn fullscreen(
+    window_mode: WindowMode,
+    size: UVector2,
+    monitor: Option<MonitorHandle>,
+) -> Option<Fullscreen> {
+    match window_mode {
+        WindowMode::Windowed => None,
+        WindowMode::Borderless => Some(Fullscreen::Borderless(monitor)),
+        WindowMode::Fullscreen => {
+            let video_mode = monitor.and_then(|monitor| {
+                monitor.video_modes().min_by_key(|mode| {
+                    let mode_size = mode.size();
+                    mode_size.width.abs_diff(size.x) + mode_size.height.abs_diff(size.y)
+                })
+            });
 
-    for mut object in &mut scene.game_objects {
-        let model = &object.model;
+            // Falls back to borderless if the video modes of the monitor are unknown.
+            Some(match video_mode {
+                Some(video_mode) => Fullscreen::Exclusive(video_mode),
+                None => Fullscreen::Borderless(None),
+            })
+        }
+    }
+}
 
-        object.vertices = Some(glium::VertexBuffer::new(&display, &model.positions).unwrap());
-        object.normals = Some(glium::VertexBuffer::new(&display, &model.normals).unwrap());
-        object.indices = Some(
-            glium::IndexBuffer::new(
-                &display,
-                glium::index::PrimitiveType::TrianglesList,
-                &model.indices,
-            )
-            .unwrap(),
-        );
+/// Creates window builder with the title, size and window mode of the config.
+fn window_builder(config: &EngineConfig, event_loop: &EventLoop<()>) -> WindowBuilder {
+    let fullscreen = fullscreen(
+        config.window_mode,
+        config.window_size,
+        event_loop.primary_monitor(),
+    );
+
+    WindowBuilder::new()
+        .with_title(config.title.as_str())
+        .with_inner_size(LogicalSize::new(config.window_size.x, config.window_size.y))
+        .with_resizable(config.resizable)
+        .with_fullscreen(fullscreen)
+}
+
+/// Applies the window changes requested by the scene since the last frame.
+fn apply_window_requests(window: &Window, scene: &mut Scene) {
+    let requests = &mut scene.window_requests;
+
+    if let Some(title) = requests.title.take() {
+        window.set_title(&title);
+    }
+    if let Some(size) = requests.size.take() {
+        window.set_inner_size(LogicalSize::new(size.x, size.y));
+    }
+    if let Some(window_mode) = requests.window_mode.take() {
+        let size = window.inner_size();
+        window.set_fullscreen(fullscreen(
+            window_mode,
+            UVector2::new(size.width, size.height),
+            window.current_monitor(),
+        ));
+    }
+    if let Some(visible) = requests.cursor_visible.take() {
+        window.set_cursor_visible(visible);
+    }
+    if let Some(grab) = requests.cursor_grab.take() {
+        let result = if grab {
+            window
+                .set_cursor_grab(CursorGrabMode::Confined)
+                .or_else(|_| window.set_cursor_grab(CursorGrabMode::Locked))
+        } else {
+            window.set_cursor_grab(CursorGrabMode::None)
+        };
 
-        object.program = Some(
-            glium::Program::from_source(&display, vertex_shader_src, fragment_shader_src, None)
-                .unwrap(),
-        );
+        // Grabbing is not supported on every platform, the game keeps running without it and can check
+        // `Scene::cursor_grabbed()`, which keeps its state if the request failed.
+        if result.is_ok() {
+            requests.cursor_grabbed = grab;
+        }
     }
+}
 
-    for i in 0..scene.game_objects.len() {
-        let object = &scene.game_objects[i];
-        (object.on_awake)(&mut scene);
+/// Main loop of the engine. The window and rendering settings are taken from `config`.
+///
+/// Panics if the engine fails to start or to draw a frame. Use `try_engine()` to handle these errors instead.
+pub fn engine(scene: Scene, config: EngineConfig) {
+    if let Err(error) = try_engine(scene, config) {
+        panic!("{}", error);
     }
+}
+
+/// Main loop of the engine. Returns once the window has been closed or `Scene::request_exit()` was called, after
+/// calling `Scene::on_exit`. Applies the asset root of the config before anything else, assets the scene loaded
+/// before the call only use it if `EngineConfig::apply_asset_root()` was called before building the scene.
+///
+/// # Errors
+/// - Window or OpenGL context creation failed
+/// - Audio device could not be opened
+/// - Shaders failed to compile or link
+/// - Vertex or index buffers of a game object could not be created
+/// - Drawing a game object or presenting the frame failed
+///
+/// # Examples
+/// ```no_run
+/// # use copper_engine::{blank_on_awake, blank_tick_update, input::InputManager, math::Vector3, structs::Scene, Camera};
+/// use copper_engine::EngineConfig;
+///
+/// let config = EngineConfig::load("settings.toml").unwrap_or_default();
+/// config.apply_asset_root();
+///
+/// // The scene is built after applying the asset root, so its assets are loaded from it.
+/// # let camera = Camera::new(0.1, 100.0, 1.0, Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), blank_tick_update, blank_on_awake);
+/// # let scene = Scene::new(vec![], vec![], InputManager::new(), camera);
+///
+/// if let Err(error) = copper_engine::try_engine(scene, config) {
+///     eprintln!("{}", error);
+/// }
+/// ```
+pub fn try_engine(mut scene: Scene, config: EngineConfig) -> Result<(), EngineError> {
+    // Assets loaded while the engine runs, like those of the asset server, use the root of the config.
+    config.apply_asset_root();
+
+    let mut event_loop = EventLoop::new();
+    let wb = window_builder(&config, &event_loop);
+    let cb = glutin::ContextBuilder::new()
+        .with_depth_buffer(config.depth_bits)
+        .with_vsync(config.vsync)
+        .with_multisampling(config.multisampling);
+    let display = Display::new(wb, cb, &event_loop)?;
 
-    // let image = image::load(
-    //     Cursor::new(&include_bytes!("../textures/test.jpg")),
-    //     image::ImageFormat::Jpeg,
-    // )
-    // .unwrap()
-    // .to_rgba8();
-    // let image_dimensions = image.dimensions();
-    // let image =
-    //     glium::texture::RawImage2d::from_raw_rgba_reversed(&image.into_raw(), image_dimensions);
-    // let texture = glium::texture::SrgbTexture2d::new(&display, image).unwrap();
+    let mut egui_glium = egui_glium::EguiGlium::new(&display, &event_loop);
+
+    let sl = Soloud::default()?;
+
+    let white_texture = upload_texture(
+        &display,
+        &Texture {
+            image: image::RgbaImage::from_pixel(1, 1, image::Rgba([255, 255, 255, 255])),
+        },
+    )?;
+
+    upload_mesh_renderers(&display, &mut scene)?;
 
     //scene.player.as_ref().unwrap().play(&scene.audio_sources[0].sound);
 
     let mut prev_time = std::time::Instant::now();
     let start_time = std::time::Instant::now();
 
-    (scene.main_camera.on_awake)(&mut scene);
+    let context = frame_context(&scene, 0);
+    awake(&mut scene, &context);
 
-    let mut drawn_frames = 0;
-    event_loop.run(move |ev, _, control_flow| {
+    let mut result = Ok(());
+    let mut drawn_frames: u64 = 0;
+    event_loop.run_return(|ev, _, control_flow| {
         let now = std::time::Instant::now();
         scene.time_since_start = (now - start_time).as_secs_f32();
 
-        let mut redraw = || {
-            let quit = false;
-
+        let mut redraw = || -> Result<(), EngineError> {
             let repaint_after = egui_glium.run(&display, |egui_ctx| {
+                if !config.debug_ui {
+                    return;
+                }
+
                 egui::Window::new("debug")
                     .fixed_size(vec2(300f32, 300f32))
                     .show(egui_ctx, |ui| {
@@ -111,7 +481,7 @@ pub fn engine(mut scene: Scene) {
                         ui.label(format!("Delta time: {:.10}", scene.delta_time));
                         ui.label(format!(
                             "Screen size: {}x{}",
-                            scene.main_camera.window_size[0], scene.main_camera.window_size[1]
+                            scene.main_camera.window_size.x, scene.main_camera.window_size.y
                         ));
 
                         ui.separator();
@@ -126,35 +496,16 @@ pub fn engine(mut scene: Scene) {
 
                         ui.separator();
 
+                        // The entity of the main camera is shown in the camera section, not as an object.
+                        let camera_entity = scene.main_camera_entity();
                         ui.collapsing(
-                            format!("Loaded objects: {}", scene.game_objects.len()),
+                            format!(
+                                "Loaded objects: {}",
+                                scene.entities().filter(|&id| id != camera_entity).count()
+                            ),
                             |ui| {
-                                for i in 0..scene.game_objects.len() {
-                                    let object = &scene.game_objects[i];
-
-                                    ui.collapsing(object.name.as_str(), |ui| {
-                                        ui.label(format!(
-                                            "Translation: {:.3?}",
-                                            object.translation
-                                        ));
-                                        ui.label(format!("Rotation: {:.3?}", object.rotation));
-                                        ui.label(format!("Scale: {:.3?}", object.scale));
-
-                                        ui.separator();
-
-                                        ui.label(format!(
-                                            "Vertices: {}",
-                                            object.model.positions.len()
-                                        ));
-                                        ui.label(format!(
-                                            "Indices: {}",
-                                            object.model.indices.len()
-                                        ));
-                                        ui.label(format!(
-                                            "Normals: {}",
-                                            object.model.normals.len()
-                                        ));
-                                    });
+                                for root in scene.roots().filter(|&id| id != camera_entity) {
+                                    entity_tree_ui(ui, &scene, root);
                                 }
                             },
                         );
@@ -211,9 +562,7 @@ pub fn engine(mut scene: Scene) {
                     });
             });
 
-            *control_flow = if quit {
-                glutin::event_loop::ControlFlow::Exit
-            } else if repaint_after.is_zero() {
+            *control_flow = if repaint_after.is_zero() {
                 display.gl_window().window().request_redraw();
                 glutin::event_loop::ControlFlow::Poll
             } else if let Some(repaint_after_instant) =
@@ -225,13 +574,15 @@ pub fn engine(mut scene: Scene) {
             };
 
             {
-                let next_frame_time =
-                    std::time::Instant::now() + std::time::Duration::from_nanos(0); //<- FPS cap
+                let next_frame_time = now + config.frame_duration().unwrap_or_default();
                 *control_flow = event_loop::ControlFlow::WaitUntil(next_frame_time);
                 scene.delta_time = (now - prev_time).as_secs_f32();
+                // Only drawn frames advance the clock, other events in between must not shorten the frame time.
+                prev_time = now;
 
                 let mut target = display.draw();
-                target.clear_color_and_depth((0.1, 0.2, 0.3, 1.0), 1.0);
+                let [r, g, b, a] = config.clear_color.to_array();
+                target.clear_color_and_depth((r, g, b, a), 1.0);
 
                 let perspective = {
                     let (width, height) = target.get_dimensions();
@@ -241,150 +592,95 @@ pub fn engine(mut scene: Scene) {
                     let z_far = scene.main_camera.z_far;
                     let z_near = scene.main_camera.z_near;
 
-                    let glm_perspective =
-                        *glm::ext::perspective(fov, aspect_ratio, z_near, z_far).as_array();
-
-                    let mut perspective = Matrix4x4::empty();
-
-                    for i in 0..4 {
-                        for j in 0..4 {
-                            perspective.matrix[i][j] = glm_perspective[i][j];
-                        }
-                    }
-
-                    perspective
-
-                    // Matrix4x4::new(
-                    //     [f * aspect_ratio, 0.0, 0.0, 0.0],
-                    //     [0.0, f, 0.0, 0.0],
-                    //     [0.0, 0.0, (z_far + z_near) / (z_near - z_far), 1.0],
-                    //     [0.0, 0.0, -(2.0 * z_far * z_near) / (z_near - z_far), 0.0],
-                    // )
+                    Matrix4x4::perspective(fov, aspect_ratio, z_near, z_far)
                 };
 
-                let light = [0.0, 10.0, -5.0f32];
-
-                let cam = scene.main_camera;
-
                 let view = scene.main_camera.look_at();
 
-                (cam.tick_update_func)(&mut scene);
+                let context = frame_context(&scene, drawn_frames);
+                tick_update(&mut scene, &context);
+                scene.update_world_matrices();
+                apply_window_requests(display.gl_window().window(), &mut scene);
 
                 // under gui layer
-                for i in 0..scene.game_objects.len() {
-                    let object = &scene.game_objects[i];
-                    (object.tick_update_func)(&mut scene);
-                }
-
-                for object in &mut scene.game_objects {
-                    let [tx, ty, tz] = object.translation;
-                    let [sx, sy, sz] = object.scale;
-                    let [rx, ry, rz] = object.rotation;
-
-                    //https://en.wikipedia.org/wiki/Rotation_matrix#Basic_rotations
-                    let position_matrix = [
-                        [
-                            sx * rz.cos() * ry.cos(),
-                            rz.cos() * ry.sin() * rx.sin() - rz.sin() * rx.cos(),
-                            rz.cos() * ry.sin() * rx.cos() + rz.sin() * rx.sin(),
-                            0.0,
-                        ],
-                        [
-                            rz.sin() * ry.cos(),
-                            sy * rz.sin() * ry.sin() * rx.sin() + rz.cos() * rx.cos(),
-                            rz.sin() * ry.sin() * rx.sin() - rz.cos() * rx.sin(),
-                            0.0,
-                        ],
-                        [
-                            -ry.sin(),
-                            ry.cos() * rx.sin(),
-                            sz * ry.cos() * rx.cos(),
-                            0.0,
-                        ],
-                        [tx, ty, tz, 1.0f32],
-                    ];
-
-                    let ambient = object.material.ambient_color;
-                    let mut diffuse = [0.0f32; 3];
-                    for i in 0..3 {
-                        diffuse[i] = ambient[i] * 2.0;
-                    }
-
-                    let params = glium::DrawParameters {
-                        depth: glium::Depth {
-                            test: glium::draw_parameters::DepthTest::IfLess,
-                            write: true,
-                            ..Default::default()
-                        },
-                        backface_culling:
-                            glium::draw_parameters::BackfaceCullingMode::CullClockwise,
-                        ..Default::default()
-                    };
-
-                    target
-                        .draw(
-                            (
-                                object.vertices.as_ref().unwrap(),
-                                object.normals.as_ref().unwrap(),
-                            ),
-                            object.indices.as_ref().unwrap(),
-                            &object.program.as_ref().unwrap(),
-                            &uniform! {
-                                model_pos_mat: position_matrix,
-                                u_light: light,
-                                u_ambient_color: ambient,
-                                u_diffuse_color: diffuse,
-                                projection: perspective.matrix,
-                                view: view.matrix,
-                            },
-                            &params,
-                        )
-                        .unwrap();
-                }
+                let drawn = run_render_systems(
+                    &mut scene,
+                    &mut RenderContext {
+                        display: &display,
+                        target: &mut target,
+                        projection: perspective,
+                        view,
+                        white_texture: &white_texture,
+                        frame: context,
+                    },
+                );
 
                 for i in 0..scene.audio_sources.len() {
                     let audio_source = &mut scene.audio_sources[i];
                     match audio_source {
                         AudioSource::Local(audio_source) => {
                             if audio_source.triggered {
-                                audio_source.sound.set_volume(audio_source.volume);
                                 let am = audio_source.amplifier;
 
-                                let [x, y, z] = audio_source.position;
+                                let Vector3 { x, y, z } = audio_source.position;
                                 let [cx, cy, cz] = scene.main_camera.position.to_array();
                                 let [dx, dy, dz] = [(x - cx) * am, (y - cy) * am, (z - cz) * am];
 
-                                sl.play_3d(&audio_source.sound, dx, dy, dz);
+                                sl.play_3d_ex(
+                                    &audio_source.sound.wav,
+                                    dx,
+                                    dy,
+                                    dz,
+                                    0.0,
+                                    0.0,
+                                    0.0,
+                                    audio_source.volume,
+                                    false,
+                                    soloud::Handle::PRIMARY,
+                                );
                                 audio_source.triggered = false;
                             }
                         }
                         AudioSource::Global(audio_source) => {
                             if audio_source.triggered {
-                                audio_source.sound.set_volume(audio_source.volume);
-
-                                sl.play(&audio_source.sound);
+                                sl.play_ex(
+                                    &audio_source.sound.wav,
+                                    audio_source.volume,
+                                    0.0,
+                                    false,
+                                    soloud::Handle::PRIMARY,
+                                );
                                 audio_source.triggered = false;
                             }
                         }
                     }
                 }
 
-                egui_glium.paint(&display, &mut target);
+                if config.debug_ui {
+                    egui_glium.paint(&display, &mut target);
+                }
 
                 // over gui layer
 
-                target.finish().unwrap();
+                target.finish()?;
+                drawn?;
 
                 drawn_frames += 1;
             }
+
+            Ok(())
         };
 
+        let mut frame_result = Ok(());
+
         match ev {
-            Event::RedrawEventsCleared if cfg!(windows) => redraw(),
-            Event::RedrawRequested(_) if !cfg!(windows) => redraw(),
+            Event::RedrawEventsCleared if cfg!(windows) => frame_result = redraw(),
+            Event::RedrawRequested(_) if !cfg!(windows) => frame_result = redraw(),
 
             Event::WindowEvent { event, .. } => match event {
-                WindowEvent::Resized(ps) => scene.main_camera.window_size = [ps.width, ps.height],
+                WindowEvent::Resized(ps) => {
+                    scene.main_camera.window_size = UVector2::new(ps.width, ps.height)
+                }
                 WindowEvent::CloseRequested | WindowEvent::Destroyed => {
                     *control_flow = glutin::event_loop::ControlFlow::Exit;
                 }
@@ -392,7 +688,8 @@ pub fn engine(mut scene: Scene) {
                     scene.input_manager.modifiers = m;
                 }
                 WindowEvent::CursorMoved { position, .. } => {
-                    scene.input_manager.mouse_position = [position.x, position.y];
+                    scene.input_manager.mouse_position =
+                        Vector2::new(position.x as f32, position.y as f32);
                     let event_response = egui_glium.on_event(&event);
 
                     if event_response {
@@ -430,6 +727,17 @@ pub fn engine(mut scene: Scene) {
             },
             _ => {}
         }
-        prev_time = now;
+
+        if let Err(error) = frame_result {
+            result = Err(error);
+            *control_flow = glutin::event_loop::ControlFlow::Exit;
+        }
+        if scene.exit_requested() {
+            *control_flow = glutin::event_loop::ControlFlow::Exit;
+        }
     });
+
+    (scene.on_exit)(&mut scene);
+
+    result
 }
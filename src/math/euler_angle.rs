@@ -1,9 +1,43 @@
+//! Euler angle describes a rotation as yaw, pitch and roll in **radian**.
+//!
+//! The engine uses right-handed coordinates with y pointing up and objects looking towards -z. Every angle turns
+//! counter-clockwise around its axis when looking from the positive end of the axis towards the origin:
+//! - yaw: Around the y-axis. Positive yaw turns the forward direction to the left, towards -x.
+//! - pitch: Around the x-axis. Positive pitch turns the forward direction up, towards +y.
+//! - roll: Around the z-axis. Positive roll tilts the up direction to the left, towards -x.
+//!
+//! Roll is applied first, then pitch and then yaw, so a rotation equals
+//! `yaw_rotation * pitch_rotation * roll_rotation`.
+
 use std::f32::consts::PI;
 
 use libm::{atan2f, copysignf};
 
-use super::{LookAtCoordinate, Vector3};
+use super::{LookAtCoordinate, Quaternion, Vector3};
 
+/// # Fields
+/// - yaw: Rotation around the y-axis in radian.
+/// - pitch: Rotation around the x-axis in radian.
+/// - roll: Rotation around the z-axis in radian.
+///
+/// Converting to a quaternion and back returns the same angles as long as the pitch is in range -π/2 to π/2 and
+/// the yaw and roll are in range -π to π. At a pitch of exactly ±π/2 only the difference of yaw and roll is kept,
+/// the roll comes back as 0. Converting to a direction and back keeps the yaw and pitch, but not the roll.
+///
+/// Usage of `::new()` is strongly recommended!
+///
+/// # Examples
+/// ```
+/// use copper_engine::math::{EulerAngle, Vector3};
+///
+/// let rotation = EulerAngle::new(0.3, -0.2, 0.1);
+/// let back = EulerAngle::from_quaternion(rotation.to_quaternion());
+///
+/// assert!((back.yaw - 0.3).abs() < 1e-5);
+/// assert!((back.pitch + 0.2).abs() < 1e-5);
+/// assert!((back.roll - 0.1).abs() < 1e-5);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct EulerAngle {
     pub yaw: f32,
     pub pitch: f32,
@@ -15,39 +49,133 @@ impl EulerAngle {
         EulerAngle { yaw, pitch, roll }
     }
 
+    /// Angles of the rotation, see the module documentation for the ranges.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::FRAC_PI_2;
+    /// use copper_engine::math::{EulerAngle, Quaternion, Vector3};
+    ///
+    /// let turn_left = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), FRAC_PI_2);
+    /// let rotation = EulerAngle::from_quaternion(turn_left);
+    ///
+    /// assert!((rotation.yaw - FRAC_PI_2).abs() < 1e-6);
+    /// assert!(rotation.pitch.abs() < 1e-6 && rotation.roll.abs() < 1e-6);
+    /// ```
+    pub fn from_quaternion(rotation: Quaternion) -> EulerAngle {
+        let Quaternion { x, y, z, w } = rotation.normalize();
+
+        //https://www.euclideanspace.com/maths/geometry/rotations/conversions/quaternionToMatrix/
+        let sinp = -2.0 * (y * z - w * x);
+
+        if sinp.abs() >= 1.0 - 1e-6 {
+            // Looking straight up or down, yaw and roll turn around the same axis.
+            let yaw = atan2f(-2.0 * (x * z - w * y), 1.0 - 2.0 * (y * y + z * z));
+
+            return EulerAngle {
+                yaw,
+                pitch: copysignf(PI / 2.0, sinp),
+                roll: 0.0,
+            };
+        }
+
+        EulerAngle {
+            yaw: atan2f(2.0 * (x * z + w * y), 1.0 - 2.0 * (x * x + y * y)),
+            pitch: sinp.asin(),
+            roll: atan2f(2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z)),
+        }
+    }
+
+    /// Same as `from_quaternion()` with the components of the quaternion.
     pub fn from_quaternion_separate(x: f32, y: f32, z: f32, w: f32) -> EulerAngle {
-        //https://stackoverflow.com/a/70462919
-
-        //roll
-        let sint_cosp = 2.0 * (w * x + y * z);
-        let cost_cosp = 1.0 - 2.0 * (x * x + y * y);
-        let roll = atan2f(sint_cosp, cost_cosp);
-
-        //pitch
-        let sinp = 2.0 * (w * y - z * x);
-        let pitch = if sinp.abs() >= 1.0 {
-            copysignf(PI / 2.0, sinp)
-        } else {
-            sinp.asin()
-        };
-
-        //yaw
-        let siny_cosp = 2.0 * (w * z + x * y);
-        let cosy_cosp = 1.0 - 2.0 * (y * y + z * z);
-        let yaw = atan2f(siny_cosp, cosy_cosp);
+        EulerAngle::from_quaternion(Quaternion::new(x, y, z, w))
+    }
 
-        EulerAngle { yaw, pitch, roll }
+    /// Rotation as quaternion.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::FRAC_PI_2;
+    /// use copper_engine::math::{EulerAngle, Vector3};
+    ///
+    /// let look_up = EulerAngle::new(0.0, FRAC_PI_2, 0.0).to_quaternion();
+    /// let forward = look_up.rotate_vector(Vector3::new(0.0, 0.0, -1.0));
+    ///
+    /// assert!((forward - Vector3::new(0.0, 1.0, 0.0)).get_length() < 1e-6);
+    /// ```
+    pub fn to_quaternion(self) -> Quaternion {
+        let yaw = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), self.yaw);
+        let pitch = Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), self.pitch);
+        let roll = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), self.roll);
+
+        yaw * pitch * roll
     }
 
-    pub fn from_look_at_coordinates(rotation: LookAtCoordinate, offset: Vector3) {
-        let x = rotation.x - offset.x;
-        let y = rotation.y - offset.y;
-        let z = (rotation.z - offset.z).acos();
+    /// Yaw and pitch that turn the forward direction, -z, towards `direction`. The roll is 0. The direction does not
+    /// have to be normalized, zero direction returns no rotation.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::FRAC_PI_4;
+    /// use copper_engine::math::{EulerAngle, Vector3};
+    ///
+    /// let rotation = EulerAngle::from_direction(Vector3::new(-1.0, 0.0, -1.0));
+    ///
+    /// assert!((rotation.yaw - FRAC_PI_4).abs() < 1e-6);
+    /// assert!(rotation.pitch.abs() < 1e-6);
+    /// ```
+    pub fn from_direction(direction: Vector3) -> EulerAngle {
+        let length = direction.get_length();
+
+        if length < f32::EPSILON {
+            return EulerAngle::default();
+        }
+
+        EulerAngle {
+            yaw: atan2f(-direction.x, -direction.z),
+            pitch: (direction.y / length).clamp(-1.0, 1.0).asin(),
+            roll: 0.0,
+        }
+    }
+
+    /// Normalized forward direction, -z, turned by the yaw and pitch. The roll does not change the direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::FRAC_PI_2;
+    /// use copper_engine::math::{EulerAngle, Vector3};
+    ///
+    /// let direction = EulerAngle::new(-FRAC_PI_2, 0.0, 0.0).to_direction();
+    ///
+    /// assert!((direction - Vector3::new(1.0, 0.0, 0.0)).get_length() < 1e-6);
+    /// ```
+    pub fn to_direction(self) -> Vector3 {
+        let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = self.pitch.sin_cos();
+
+        Vector3::new(-cos_pitch * sin_yaw, sin_pitch, -cos_pitch * cos_yaw)
+    }
+
+    /// Yaw and pitch of a camera at `offset` looking at `target`. The roll is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{EulerAngle, LookAtCoordinate, Vector3};
+    ///
+    /// let offset = Vector3::new(0.0, 2.0, 5.0);
+    /// let rotation = EulerAngle::new(0.7, -0.4, 0.0);
+    /// let target = LookAtCoordinate::from_euler_angle(rotation, offset);
+    /// let back = EulerAngle::from_look_at_coordinates(target, offset);
+    ///
+    /// assert!((back.yaw - 0.7).abs() < 1e-5 && (back.pitch + 0.4).abs() < 1e-5);
+    /// ```
+    pub fn from_look_at_coordinates(target: LookAtCoordinate, offset: Vector3) -> EulerAngle {
+        EulerAngle::from_direction(target.to_vector3() - offset)
+    }
+}
 
-        /*
-        let x = rotation.yaw.cos() * rotation.pitch.cos() + offset.x;
-        let y = rotation.yaw.sin() * rotation.pitch.cos() + offset.y;
-        let z = rotation.pitch.sin() + offset.z;
-        */
+impl From<Quaternion> for EulerAngle {
+    fn from(rotation: Quaternion) -> Self {
+        EulerAngle::from_quaternion(rotation)
     }
 }
@@ -0,0 +1,196 @@
+//! Axis aligned bounding box, a box whose sides are parallel to the x, y and z axes.
+
+use super::Sphere;
+use crate::math::{Matrix4x4, Vector3};
+
+/// # Fields
+/// - min: Corner with the smallest x, y and z.
+/// - max: Corner with the largest x, y and z.
+///
+/// Boxes are solid, points on the sides are inside.
+///
+/// Usage of `::new()` is strongly recommended!
+///
+/// # Examples
+/// ```
+/// use copper_engine::math::{Aabb, Vector3};
+///
+/// let aabb = Aabb::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(-1.0, 0.0, 1.0));
+///
+/// assert_eq!(aabb.min, Vector3::new(-1.0, 0.0, 1.0));
+/// assert_eq!(aabb.center(), Vector3::new(0.0, 1.0, 2.0));
+/// assert!(aabb.contains_point(Vector3::new(1.0, 1.0, 1.0)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+impl Aabb {
+    /// creates box with `corner` and `opposite_corner` as opposite corners, in any order.
+    pub fn new(corner: Vector3, opposite_corner: Vector3) -> Aabb {
+        Aabb {
+            min: corner.min(opposite_corner),
+            max: corner.max(opposite_corner),
+        }
+    }
+
+    /// creates box reaching `half_extents` from `center` in both directions of every axis.
+    pub fn from_center_half_extents(center: Vector3, half_extents: Vector3) -> Aabb {
+        let half_extents = half_extents.abs();
+
+        Aabb {
+            min: center - half_extents,
+            max: center + half_extents,
+        }
+    }
+
+    /// Smallest box containing every point, `None` if there are no points.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Aabb, Vector3};
+    ///
+    /// let points = [Vector3::new(1.0, -2.0, 0.0), Vector3::new(-1.0, 3.0, 0.5)];
+    /// let aabb = Aabb::from_points(points).unwrap();
+    ///
+    /// assert_eq!(aabb, Aabb::new(Vector3::new(-1.0, -2.0, 0.0), Vector3::new(1.0, 3.0, 0.5)));
+    /// assert_eq!(Aabb::from_points([]), None);
+    /// ```
+    pub fn from_points(points: impl IntoIterator<Item = Vector3>) -> Option<Aabb> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold(Aabb::new(first, first), |aabb, point| Aabb {
+            min: aabb.min.min(point),
+            max: aabb.max.max(point),
+        }))
+    }
+
+    pub fn center(self) -> Vector3 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Distance from the center to the sides on every axis.
+    pub fn half_extents(self) -> Vector3 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Width, height and depth of the box.
+    pub fn size(self) -> Vector3 {
+        self.max - self.min
+    }
+
+    /// The 8 corners of the box.
+    pub fn corners(self) -> [Vector3; 8] {
+        let Aabb { min, max } = self;
+
+        [
+            Vector3::new(min.x, min.y, min.z),
+            Vector3::new(max.x, min.y, min.z),
+            Vector3::new(min.x, max.y, min.z),
+            Vector3::new(max.x, max.y, min.z),
+            Vector3::new(min.x, min.y, max.z),
+            Vector3::new(max.x, min.y, max.z),
+            Vector3::new(min.x, max.y, max.z),
+            Vector3::new(max.x, max.y, max.z),
+        ]
+    }
+
+    /// Smallest box containing both boxes.
+    pub fn union(self, other: Aabb) -> Aabb {
+        Aabb {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    pub fn contains_point(self, point: Vector3) -> bool {
+        self.closest_point(point) == point
+    }
+
+    /// Whether `other` is completely inside the box.
+    pub fn contains_aabb(self, other: Aabb) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    /// Whether the boxes overlap or touch.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Aabb, Vector3};
+    ///
+    /// let a = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 2.0, 2.0));
+    /// let b = Aabb::new(Vector3::new(1.0, 1.0, 1.0), Vector3::new(3.0, 3.0, 3.0));
+    /// let c = Aabb::new(Vector3::new(2.5, 0.0, 0.0), Vector3::new(3.0, 1.0, 1.0));
+    ///
+    /// assert!(a.intersects_aabb(b));
+    /// assert!(!a.intersects_aabb(c));
+    /// ```
+    pub fn intersects_aabb(self, other: Aabb) -> bool {
+        (0..3).all(|axis| self.min[axis] <= other.max[axis] && other.min[axis] <= self.max[axis])
+    }
+
+    /// Same as `Sphere::intersects_aabb()`.
+    pub fn intersects_sphere(self, sphere: Sphere) -> bool {
+        sphere.intersects_aabb(self)
+    }
+
+    /// Point of the box closest to `point`, the point itself if it is inside.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Aabb, Vector3};
+    ///
+    /// let aabb = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    ///
+    /// assert_eq!(aabb.closest_point(Vector3::new(5.0, 0.5, -3.0)), Vector3::new(1.0, 0.5, -1.0));
+    /// ```
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        point.clamp(self.min, self.max)
+    }
+
+    /// Smallest axis aligned box containing the box transformed by `matrix`. The matrix has to be affine, like the
+    /// matrices of `Transform::to_matrix()`, projections are not supported.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::FRAC_PI_4;
+    /// use copper_engine::math::{Aabb, Quaternion, Transform, Vector3};
+    ///
+    /// let aabb = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    /// let transform = Transform::new(
+    ///     Vector3::new(10.0, 0.0, 0.0),
+    ///     Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), FRAC_PI_4),
+    ///     Vector3::new(1.0, 2.0, 1.0),
+    /// );
+    ///
+    /// let moved = aabb.transform(transform.to_matrix());
+    /// let diagonal = 2.0_f32.sqrt();
+    ///
+    /// assert!(moved.min.approx_eq(Vector3::new(10.0 - diagonal, -2.0, -diagonal), 1e-5));
+    /// assert!(moved.max.approx_eq(Vector3::new(10.0 + diagonal, 2.0, diagonal), 1e-5));
+    /// ```
+    pub fn transform(self, matrix: Matrix4x4) -> Aabb {
+        let m = matrix.matrix;
+        let mut min = [m[3][0], m[3][1], m[3][2]];
+        let mut max = min;
+
+        // Arvo's method, every row moves the box by the row scaled with the smaller or larger side of the box.
+        for (row, values) in m.iter().take(3).enumerate() {
+            for column in 0..3 {
+                let from_min = values[column] * self.min[row];
+                let from_max = values[column] * self.max[row];
+
+                min[column] += from_min.min(from_max);
+                max[column] += from_min.max(from_max);
+            }
+        }
+
+        Aabb {
+            min: min.into(),
+            max: max.into(),
+        }
+    }
+}
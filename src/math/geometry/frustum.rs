@@ -0,0 +1,136 @@
+//! Frustum is the space a camera sees, the truncated pyramid between its near and far clipping planes.
+
+use super::{Aabb, Plane, Sphere};
+use crate::math::{Matrix4x4, Vector3};
+
+/// # Fields
+/// - planes: Left, right, bottom, top, near and far planes, in that order. The planes face into the frustum.
+///
+/// The intersection tests are meant for culling: they never miss shapes inside the frustum, but may report boxes
+/// and spheres just outside of a corner of the frustum as intersecting.
+///
+/// Usage of `::from_matrix()` is strongly recommended!
+///
+/// # Examples
+/// ```
+/// use std::f32::consts::FRAC_PI_2;
+/// use copper_engine::math::{Frustum, Matrix4x4, Sphere, Vector3};
+///
+/// // Camera at the origin looking towards -z.
+/// let view = Matrix4x4::look_at(
+///     Vector3::new(0.0, 0.0, 0.0),
+///     Vector3::new(0.0, 0.0, -1.0),
+///     Vector3::new(0.0, 1.0, 0.0),
+/// );
+/// let projection = Matrix4x4::perspective(FRAC_PI_2, 1.0, 0.1, 100.0);
+/// let frustum = Frustum::from_matrix(view * projection);
+///
+/// assert!(frustum.intersects_sphere(Sphere::new(Vector3::new(0.0, 0.0, -10.0), 1.0)));
+/// assert!(!frustum.intersects_sphere(Sphere::new(Vector3::new(0.0, 0.0, 10.0), 1.0)));
+/// assert!(!frustum.intersects_sphere(Sphere::new(Vector3::new(0.0, 0.0, -200.0), 1.0)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Frustum {
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// Frustum of the view projection matrix `view * projection`, or of `model * view * projection` for a frustum in
+    /// the local space of a model. Uses the OpenGL clip space, where the depth goes from `-w` to `w`.
+    pub fn from_matrix(view_projection: Matrix4x4) -> Frustum {
+        let m = view_projection.matrix;
+
+        // Gribb and Hartmann, with row vectors the clip coordinates are the dot products with the columns.
+        let column = |index: usize| [m[0][index], m[1][index], m[2][index], m[3][index]];
+        let [x, y, z, w] = [column(0), column(1), column(2), column(3)];
+
+        let plane = |sign: f32, column: [f32; 4]| {
+            let [a, b, c, d] = [0, 1, 2, 3].map(|i| w[i] + sign * column[i]);
+
+            // a * x + b * y + c * z + d >= 0 inside the frustum.
+            Plane::new(Vector3::new(a, b, c), -d)
+        };
+
+        Frustum {
+            planes: [
+                plane(1.0, x),
+                plane(-1.0, x),
+                plane(1.0, y),
+                plane(-1.0, y),
+                plane(1.0, z),
+                plane(-1.0, z),
+            ],
+        }
+    }
+
+    pub fn contains_point(self, point: Vector3) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(point) >= 0.0)
+    }
+
+    /// Whether the sphere is at least partly inside the frustum.
+    pub fn intersects_sphere(self, sphere: Sphere) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(sphere.center) >= -sphere.radius)
+    }
+
+    /// Whether the sphere is completely inside the frustum.
+    pub fn contains_sphere(self, sphere: Sphere) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(sphere.center) >= sphere.radius)
+    }
+
+    /// Whether the box is at least partly inside the frustum. Checks the corner of the box furthest in front of
+    /// every plane.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::FRAC_PI_2;
+    /// use copper_engine::math::{Aabb, Frustum, Matrix4x4, Vector3};
+    ///
+    /// let projection = Matrix4x4::perspective(FRAC_PI_2, 1.0, 0.1, 100.0);
+    /// let frustum = Frustum::from_matrix(projection);
+    ///
+    /// let ahead = Aabb::new(Vector3::new(-1.0, -1.0, -6.0), Vector3::new(1.0, 1.0, -4.0));
+    /// let crossing_side = Aabb::new(Vector3::new(4.0, -1.0, -6.0), Vector3::new(8.0, 1.0, -4.0));
+    /// let behind = Aabb::new(Vector3::new(-1.0, -1.0, 4.0), Vector3::new(1.0, 1.0, 6.0));
+    ///
+    /// assert!(frustum.intersects_aabb(ahead) && frustum.contains_aabb(ahead));
+    /// assert!(frustum.intersects_aabb(crossing_side) && !frustum.contains_aabb(crossing_side));
+    /// assert!(!frustum.intersects_aabb(behind));
+    /// ```
+    pub fn intersects_aabb(self, aabb: Aabb) -> bool {
+        self.planes.iter().all(|plane| {
+            let furthest = select(plane.normal, aabb.max, aabb.min);
+            plane.signed_distance(furthest) >= 0.0
+        })
+    }
+
+    /// Whether the box is completely inside the frustum. Checks the corner of the box furthest behind every plane.
+    pub fn contains_aabb(self, aabb: Aabb) -> bool {
+        self.planes.iter().all(|plane| {
+            let nearest = select(plane.normal, aabb.min, aabb.max);
+            plane.signed_distance(nearest) >= 0.0
+        })
+    }
+}
+
+/// Component of `positive` where `normal` is positive, otherwise component of `negative`.
+fn select(normal: Vector3, positive: Vector3, negative: Vector3) -> Vector3 {
+    let pick = |normal: f32, positive: f32, negative: f32| {
+        if normal >= 0.0 {
+            positive
+        } else {
+            negative
+        }
+    };
+
+    Vector3::new(
+        pick(normal.x, positive.x, negative.x),
+        pick(normal.y, positive.y, negative.y),
+        pick(normal.z, positive.z, negative.z),
+    )
+}
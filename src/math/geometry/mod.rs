@@ -0,0 +1,24 @@
+//! Shapes for picking, culling and collision checks, and intersection tests between them. The shapes are plain
+//! values in the same space as their coordinates, usually world space, and need no window or GPU.
+//!
+//! Tests with a `Ray` are methods of the ray and return the distance to the hit, `None` if the ray misses. Tests
+//! between two shapes are methods of either shape and return whether the shapes touch.
+
+pub mod aabb;
+pub mod frustum;
+pub mod obb;
+pub mod plane;
+pub mod ray;
+pub mod sphere;
+pub mod triangle;
+
+pub use aabb::Aabb;
+pub use frustum::Frustum;
+pub use obb::Obb;
+pub use plane::Plane;
+pub use ray::Ray;
+pub use sphere::Sphere;
+pub use triangle::Triangle;
+
+/// Smallest value treated as non-zero by the tests, like the determinant of a ray parallel to a triangle.
+const EPSILON: f32 = 1e-6;
@@ -0,0 +1,131 @@
+//! Oriented bounding box, a box that can be rotated freely.
+
+use super::{Aabb, Sphere};
+use crate::math::{Quaternion, Transform, Vector3};
+
+/// # Fields
+/// - center: Center of the box.
+/// - half_extents: Distance from the center to the sides along the local x, y and z axes of the box.
+/// - rotation: Rotation turning the world axes into the local axes of the box.
+///
+/// Boxes are solid, points on the sides are inside.
+///
+/// Usage of `::new()` is strongly recommended!
+///
+/// # Examples
+/// ```
+/// use std::f32::consts::FRAC_PI_4;
+/// use copper_engine::math::{Obb, Quaternion, Vector3};
+///
+/// let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_4);
+/// let obb = Obb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 0.5, 0.5), rotation);
+///
+/// // Along the turned x-axis of the box, but outside of the box along the world x-axis.
+/// assert!(obb.contains_point(Vector3::new(1.0, 1.0, 0.0)));
+/// assert!(!obb.contains_point(Vector3::new(1.9, 0.0, 0.0)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Obb {
+    pub center: Vector3,
+    pub half_extents: Vector3,
+    pub rotation: Quaternion,
+}
+
+impl Obb {
+    /// creates box around `center`. Negative half extents are made positive.
+    pub fn new(center: Vector3, half_extents: Vector3, rotation: Quaternion) -> Obb {
+        Obb {
+            center,
+            half_extents: half_extents.abs(),
+            rotation,
+        }
+    }
+
+    /// Box `aabb` becomes when it is scaled, rotated and moved by `transform`, for example the bounds of a model
+    /// placed in the world.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Aabb, Obb, Quaternion, Transform, Vector3};
+    ///
+    /// let bounds = Aabb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(2.0, 2.0, 2.0));
+    /// let transform = Transform::new(Vector3::new(0.0, 5.0, 0.0), Quaternion::identity(), Vector3::new(2.0, 1.0, 1.0));
+    ///
+    /// let obb = Obb::from_aabb(bounds, transform);
+    ///
+    /// assert_eq!(obb.center, Vector3::new(2.0, 6.0, 1.0));
+    /// assert_eq!(obb.half_extents, Vector3::new(2.0, 1.0, 1.0));
+    /// ```
+    pub fn from_aabb(aabb: Aabb, transform: Transform) -> Obb {
+        Obb::new(
+            transform.transform_point(aabb.center()),
+            aabb.half_extents().component_mul(transform.scale),
+            transform.rotation,
+        )
+    }
+
+    /// Normalized local x, y and z axes of the box in world space.
+    pub fn axes(self) -> [Vector3; 3] {
+        [
+            self.rotation.rotate_vector(Vector3::new(1.0, 0.0, 0.0)),
+            self.rotation.rotate_vector(Vector3::new(0.0, 1.0, 0.0)),
+            self.rotation.rotate_vector(Vector3::new(0.0, 0.0, 1.0)),
+        ]
+    }
+
+    /// The 8 corners of the box in world space.
+    pub fn corners(self) -> [Vector3; 8] {
+        self.local_aabb()
+            .corners()
+            .map(|corner| self.center + self.rotation.rotate_vector(corner))
+    }
+
+    pub fn contains_point(self, point: Vector3) -> bool {
+        self.local_aabb().contains_point(self.to_local(point))
+    }
+
+    /// Whether the box and the sphere overlap or touch.
+    pub fn intersects_sphere(self, sphere: Sphere) -> bool {
+        sphere.contains_point(self.closest_point(sphere.center))
+    }
+
+    /// Point of the box closest to `point`, the point itself if it is inside.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::FRAC_PI_2;
+    /// use copper_engine::math::{Obb, Quaternion, Vector3};
+    ///
+    /// // Long side turned from the x-axis to the z-axis.
+    /// let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), FRAC_PI_2);
+    /// let obb = Obb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(3.0, 1.0, 1.0), rotation);
+    ///
+    /// let closest = obb.closest_point(Vector3::new(0.0, 0.0, 10.0));
+    /// assert!(closest.approx_eq(Vector3::new(0.0, 0.0, 3.0), 1e-5));
+    /// ```
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        let closest = self.local_aabb().closest_point(self.to_local(point));
+
+        self.center + self.rotation.rotate_vector(closest)
+    }
+
+    /// Smallest axis aligned box containing the box.
+    pub fn to_aabb(self) -> Aabb {
+        let [x_axis, y_axis, z_axis] = self.axes();
+        let half_extents = x_axis.abs() * self.half_extents.x
+            + y_axis.abs() * self.half_extents.y
+            + z_axis.abs() * self.half_extents.z;
+
+        Aabb::from_center_half_extents(self.center, half_extents)
+    }
+
+    /// The box without rotation, centered at the origin.
+    fn local_aabb(self) -> Aabb {
+        Aabb::from_center_half_extents(Vector3::new(0.0, 0.0, 0.0), self.half_extents)
+    }
+
+    /// `point` relative to the center, turned into the local axes of the box.
+    fn to_local(self, point: Vector3) -> Vector3 {
+        self.rotation.inverse().rotate_vector(point - self.center)
+    }
+}
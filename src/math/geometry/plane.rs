@@ -0,0 +1,99 @@
+//! Plane is an infinite flat surface dividing space into a front and a back side.
+
+use crate::math::Vector3;
+
+/// # Fields
+/// - normal: Normalized direction the front side of the plane faces.
+/// - distance: Distance of the plane from the world origin (0, 0, 0) along `normal`. Points of the plane satisfy
+///   `normal.dot(point) == distance`.
+///
+/// Usage of `::new()` is strongly recommended!
+///
+/// # Examples
+/// ```
+/// use copper_engine::math::{Plane, Vector3};
+///
+/// let plane = Plane::new(Vector3::new(0.0, 2.0, 0.0), 4.0);
+///
+/// assert_eq!(plane.normal, Vector3::new(0.0, 1.0, 0.0));
+/// assert_eq!(plane.signed_distance(Vector3::new(5.0, 3.0, 0.0)), 1.0);
+/// assert_eq!(plane.signed_distance(Vector3::new(5.0, 0.0, 0.0)), -2.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plane {
+    pub normal: Vector3,
+    pub distance: f32,
+}
+
+impl Plane {
+    /// creates plane from the plane equation `normal.dot(point) == distance`. Normal that is not normalized scales
+    /// the distance the same way, a zero normal is kept as is.
+    pub fn new(normal: Vector3, distance: f32) -> Plane {
+        let length = normal.get_length();
+
+        if length == 0.0 {
+            return Plane { normal, distance };
+        }
+
+        Plane {
+            normal: normal / length,
+            distance: distance / length,
+        }
+    }
+
+    /// creates plane going through `point` and facing `normal`.
+    pub fn from_point_normal(point: Vector3, normal: Vector3) -> Plane {
+        let normal = normal.normalize();
+
+        Plane {
+            normal,
+            distance: normal.dot(point),
+        }
+    }
+
+    /// creates plane going through the points. The front side is the side the points are counter-clockwise from.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Plane, Vector3};
+    ///
+    /// let plane = Plane::from_points(
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 1.0),
+    ///     Vector3::new(1.0, 1.0, 0.0),
+    /// );
+    ///
+    /// assert_eq!(plane.normal, Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(plane.distance, 1.0);
+    /// ```
+    pub fn from_points(a: Vector3, b: Vector3, c: Vector3) -> Plane {
+        Plane::from_point_normal(a, (b - a).cross_product(c - a))
+    }
+
+    /// Distance of `point` from the plane, positive in front of the plane and negative behind it.
+    pub fn signed_distance(self, point: Vector3) -> f32 {
+        self.normal.dot(point) - self.distance
+    }
+
+    /// Point of the plane closest to `point`.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Plane, Vector3};
+    ///
+    /// let wall = Plane::from_point_normal(Vector3::new(0.0, 0.0, -5.0), Vector3::new(0.0, 0.0, 1.0));
+    ///
+    /// assert_eq!(wall.closest_point(Vector3::new(1.0, 2.0, 3.0)), Vector3::new(1.0, 2.0, -5.0));
+    /// ```
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// Plane at the same place facing the other way.
+    pub fn flip(self) -> Plane {
+        Plane {
+            normal: -self.normal,
+            distance: -self.distance,
+        }
+    }
+}
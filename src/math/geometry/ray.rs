@@ -0,0 +1,246 @@
+//! Ray is a half line starting from a point, used for picking and line of sight checks.
+
+use super::{Aabb, Obb, Plane, Sphere, Triangle, EPSILON};
+use crate::math::Vector3;
+
+/// # Fields
+/// - origin: Point the ray starts from.
+/// - direction: Normalized direction of the ray.
+///
+/// The intersection methods return the distance from `origin` along `direction` to the first point the ray hits, or
+/// `None` if it misses. Rays starting inside an `Aabb`, `Sphere` or `Obb` hit it at distance 0.
+///
+/// Usage of `::new()` is strongly recommended!
+///
+/// # Examples
+/// ```
+/// use copper_engine::math::{Aabb, Ray, Vector3};
+///
+/// let ray = Ray::new(Vector3::new(0.0, 0.0, 10.0), Vector3::new(0.0, 0.0, -1.0));
+/// let crate_box = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+///
+/// let distance = ray.intersect_aabb(crate_box).unwrap();
+/// assert!(ray.at(distance).approx_eq(Vector3::new(0.0, 0.0, 1.0), 1e-6));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray {
+    pub origin: Vector3,
+    pub direction: Vector3,
+}
+
+impl Ray {
+    /// creates ray from `origin` towards `direction`. The direction is normalized.
+    pub fn new(origin: Vector3, direction: Vector3) -> Ray {
+        Ray {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    /// creates ray from `from` passing through `to`.
+    pub fn from_points(from: Vector3, to: Vector3) -> Ray {
+        Ray::new(from, to - from)
+    }
+
+    /// Point `distance` away from the origin along the ray.
+    pub fn at(self, distance: f32) -> Vector3 {
+        self.origin + self.direction * distance
+    }
+
+    /// Point of the ray closest to `point`. Points behind the ray are closest to its origin.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Ray, Vector3};
+    ///
+    /// let ray = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    ///
+    /// assert_eq!(ray.closest_point(Vector3::new(3.0, 2.0, 0.0)), Vector3::new(3.0, 0.0, 0.0));
+    /// assert_eq!(ray.closest_point(Vector3::new(-3.0, 2.0, 0.0)), Vector3::new(0.0, 0.0, 0.0));
+    /// ```
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        self.at((point - self.origin).dot(self.direction).max(0.0))
+    }
+
+    /// Distance to the first hit with the box, using the slab method.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Aabb, Ray, Vector3};
+    ///
+    /// let aabb = Aabb::new(Vector3::new(2.0, -1.0, -1.0), Vector3::new(4.0, 1.0, 1.0));
+    ///
+    /// let towards = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    /// let away = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(-1.0, 0.0, 0.0));
+    /// let past = Ray::new(Vector3::new(0.0, 2.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+    ///
+    /// assert_eq!(towards.intersect_aabb(aabb), Some(2.0));
+    /// assert_eq!(away.intersect_aabb(aabb), None);
+    /// assert_eq!(past.intersect_aabb(aabb), None);
+    /// ```
+    pub fn intersect_aabb(self, aabb: Aabb) -> Option<f32> {
+        let mut near = 0.0_f32;
+        let mut far = f32::INFINITY;
+
+        for axis in 0..3 {
+            let origin = self.origin[axis];
+            let direction = self.direction[axis];
+            let (min, max) = (aabb.min[axis], aabb.max[axis]);
+
+            // Parallel to the slab, the ray either stays between its sides or never enters it.
+            if direction.abs() < EPSILON {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+
+            let first = (min - origin) / direction;
+            let second = (max - origin) / direction;
+
+            near = near.max(first.min(second));
+            far = far.min(first.max(second));
+
+            if near > far {
+                return None;
+            }
+        }
+
+        Some(near)
+    }
+
+    /// Distance to the first hit with the box.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::FRAC_PI_4;
+    /// use copper_engine::math::{Obb, Quaternion, Ray, Vector3};
+    ///
+    /// // Cube turned by 45 degrees around y, its edge points towards the ray.
+    /// let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), FRAC_PI_4);
+    /// let obb = Obb::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0), rotation);
+    /// let ray = Ray::new(Vector3::new(0.0, 0.0, 5.0), Vector3::new(0.0, 0.0, -1.0));
+    ///
+    /// let distance = ray.intersect_obb(obb).unwrap();
+    /// assert!((distance - (5.0 - 2.0_f32.sqrt())).abs() < 1e-5);
+    /// ```
+    pub fn intersect_obb(self, obb: Obb) -> Option<f32> {
+        let to_local = obb.rotation.inverse();
+        let local = Ray {
+            origin: to_local.rotate_vector(self.origin - obb.center),
+            direction: to_local.rotate_vector(self.direction),
+        };
+
+        local.intersect_aabb(Aabb::from_center_half_extents(
+            Vector3::new(0.0, 0.0, 0.0),
+            obb.half_extents,
+        ))
+    }
+
+    /// Distance to the first hit with the sphere.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Ray, Sphere, Vector3};
+    ///
+    /// let sphere = Sphere::new(Vector3::new(0.0, 0.0, -5.0), 1.0);
+    ///
+    /// let forward = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+    /// let inside = Ray::new(Vector3::new(0.0, 0.0, -5.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let up = Ray::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    ///
+    /// assert_eq!(forward.intersect_sphere(sphere), Some(4.0));
+    /// assert_eq!(inside.intersect_sphere(sphere), Some(0.0));
+    /// assert_eq!(up.intersect_sphere(sphere), None);
+    /// ```
+    pub fn intersect_sphere(self, sphere: Sphere) -> Option<f32> {
+        let to_center = sphere.center - self.origin;
+        let projection = to_center.dot(self.direction);
+        let distance_squared = to_center.dot(to_center) - projection * projection;
+        let radius_squared = sphere.radius * sphere.radius;
+
+        if distance_squared > radius_squared {
+            return None;
+        }
+
+        let half_chord = (radius_squared - distance_squared).sqrt();
+
+        if projection + half_chord < 0.0 {
+            return None;
+        }
+
+        Some((projection - half_chord).max(0.0))
+    }
+
+    /// Distance to the plane, hit from either side. Rays parallel to the plane miss it.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Plane, Ray, Vector3};
+    ///
+    /// let ground = Plane::from_point_normal(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+    /// let ray = Ray::new(Vector3::new(0.0, 3.0, 0.0), Vector3::new(0.0, -1.0, 1.0));
+    ///
+    /// let hit = ray.at(ray.intersect_plane(ground).unwrap());
+    /// assert!(hit.approx_eq(Vector3::new(0.0, 0.0, 3.0), 1e-5));
+    /// ```
+    pub fn intersect_plane(self, plane: Plane) -> Option<f32> {
+        let facing = plane.normal.dot(self.direction);
+
+        if facing.abs() < EPSILON {
+            return None;
+        }
+
+        let distance = -plane.signed_distance(self.origin) / facing;
+
+        (distance >= 0.0).then_some(distance)
+    }
+
+    /// Distance to the triangle, hit from either side, using the Möller-Trumbore algorithm.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Ray, Triangle, Vector3};
+    ///
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(-1.0, 0.0, -2.0),
+    ///     Vector3::new(1.0, 0.0, -2.0),
+    ///     Vector3::new(0.0, 2.0, -2.0),
+    /// );
+    ///
+    /// let hit = Ray::new(Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+    /// let miss = Ray::new(Vector3::new(0.0, 3.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+    ///
+    /// assert_eq!(hit.intersect_triangle(triangle), Some(2.0));
+    /// assert_eq!(miss.intersect_triangle(triangle), None);
+    /// ```
+    pub fn intersect_triangle(self, triangle: Triangle) -> Option<f32> {
+        let edge_1 = triangle.b - triangle.a;
+        let edge_2 = triangle.c - triangle.a;
+
+        let p = self.direction.cross_product(edge_2);
+        let determinant = edge_1.dot(p);
+
+        if determinant.abs() < EPSILON {
+            return None;
+        }
+
+        let inverse_determinant = 1.0 / determinant;
+        let to_origin = self.origin - triangle.a;
+
+        let u = to_origin.dot(p) * inverse_determinant;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = to_origin.cross_product(edge_1);
+        let v = self.direction.dot(q) * inverse_determinant;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let distance = edge_2.dot(q) * inverse_determinant;
+
+        (distance >= 0.0).then_some(distance)
+    }
+}
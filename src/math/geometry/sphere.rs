@@ -0,0 +1,96 @@
+//! Sphere is every point within a radius of its center.
+
+use super::Aabb;
+use crate::math::Vector3;
+
+/// # Fields
+/// - center: Center of the sphere.
+/// - radius: Distance from the center to the surface.
+///
+/// Spheres are solid, points on the surface are inside.
+///
+/// Usage of `::new()` is strongly recommended!
+///
+/// # Examples
+/// ```
+/// use copper_engine::math::{Sphere, Vector3};
+///
+/// let a = Sphere::new(Vector3::new(0.0, 0.0, 0.0), 1.0);
+/// let b = Sphere::new(Vector3::new(1.5, 0.0, 0.0), 1.0);
+/// let c = Sphere::new(Vector3::new(0.0, 3.0, 0.0), 1.0);
+///
+/// assert!(a.intersects_sphere(b));
+/// assert!(!a.intersects_sphere(c));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sphere {
+    pub center: Vector3,
+    pub radius: f32,
+}
+
+impl Sphere {
+    /// creates sphere around `center`. Negative radius is made positive.
+    pub fn new(center: Vector3, radius: f32) -> Sphere {
+        Sphere {
+            center,
+            radius: radius.abs(),
+        }
+    }
+
+    /// Smallest sphere containing the box.
+    pub fn from_aabb(aabb: Aabb) -> Sphere {
+        Sphere::new(aabb.center(), aabb.half_extents().get_length())
+    }
+
+    pub fn contains_point(self, point: Vector3) -> bool {
+        self.center.distance(point) <= self.radius
+    }
+
+    /// Whether the spheres overlap or touch.
+    pub fn intersects_sphere(self, other: Sphere) -> bool {
+        self.center.distance(other.center) <= self.radius + other.radius
+    }
+
+    /// Whether the sphere and the box overlap or touch.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Aabb, Sphere, Vector3};
+    ///
+    /// let aabb = Aabb::new(Vector3::new(-1.0, -1.0, -1.0), Vector3::new(1.0, 1.0, 1.0));
+    ///
+    /// // Near the corner of the box, but not touching it.
+    /// let sphere = Sphere::new(Vector3::new(2.0, 2.0, 2.0), 1.5);
+    /// assert!(!sphere.intersects_aabb(aabb));
+    /// assert!(Sphere::new(sphere.center, 1.8).intersects_aabb(aabb));
+    /// ```
+    pub fn intersects_aabb(self, aabb: Aabb) -> bool {
+        self.contains_point(aabb.closest_point(self.center))
+    }
+
+    /// Point of the sphere closest to `point`, the point itself if it is inside.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Sphere, Vector3};
+    ///
+    /// let sphere = Sphere::new(Vector3::new(0.0, 1.0, 0.0), 2.0);
+    ///
+    /// assert_eq!(sphere.closest_point(Vector3::new(0.0, 11.0, 0.0)), Vector3::new(0.0, 3.0, 0.0));
+    /// assert_eq!(sphere.closest_point(Vector3::new(0.5, 1.0, 0.0)), Vector3::new(0.5, 1.0, 0.0));
+    /// ```
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        if self.contains_point(point) {
+            return point;
+        }
+
+        self.center + (point - self.center).normalize() * self.radius
+    }
+
+    /// Smallest axis aligned box containing the sphere.
+    pub fn to_aabb(self) -> Aabb {
+        let radius = Vector3::new(self.radius, self.radius, self.radius);
+
+        Aabb::from_center_half_extents(self.center, radius)
+    }
+}
@@ -0,0 +1,125 @@
+//! Triangle of three points, like the faces of a model.
+
+use super::Plane;
+use crate::math::Vector3;
+
+/// # Fields
+/// - a: First corner.
+/// - b: Second corner.
+/// - c: Third corner.
+///
+/// The front side of the triangle is the side its corners are counter-clockwise from, same as the faces of models.
+///
+/// Usage of `::new()` is strongly recommended!
+///
+/// # Examples
+/// ```
+/// use copper_engine::math::{Triangle, Vector3};
+///
+/// let triangle = Triangle::new(
+///     Vector3::new(0.0, 0.0, 0.0),
+///     Vector3::new(2.0, 0.0, 0.0),
+///     Vector3::new(0.0, 2.0, 0.0),
+/// );
+///
+/// assert_eq!(triangle.normal(), Vector3::new(0.0, 0.0, 1.0));
+/// assert_eq!(triangle.area(), 2.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Triangle {
+    pub a: Vector3,
+    pub b: Vector3,
+    pub c: Vector3,
+}
+
+impl Triangle {
+    pub fn new(a: Vector3, b: Vector3, c: Vector3) -> Triangle {
+        Triangle { a, b, c }
+    }
+
+    /// Normalized direction the front side faces. Zero if the corners are on one line.
+    pub fn normal(self) -> Vector3 {
+        (self.b - self.a).cross_product(self.c - self.a).normalize()
+    }
+
+    pub fn area(self) -> f32 {
+        (self.b - self.a)
+            .cross_product(self.c - self.a)
+            .get_length()
+            * 0.5
+    }
+
+    /// Average of the corners.
+    pub fn centroid(self) -> Vector3 {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    /// Plane the triangle lies on, facing the same way.
+    pub fn plane(self) -> Plane {
+        Plane::from_points(self.a, self.b, self.c)
+    }
+
+    /// Point of the triangle closest to `point`, found by checking which corner or edge region `point` is in.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Triangle, Vector3};
+    ///
+    /// let triangle = Triangle::new(
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(2.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 2.0, 0.0),
+    /// );
+    ///
+    /// // Above the face, next to an edge and past a corner.
+    /// assert_eq!(triangle.closest_point(Vector3::new(0.5, 0.5, 3.0)), Vector3::new(0.5, 0.5, 0.0));
+    /// assert_eq!(triangle.closest_point(Vector3::new(1.0, -1.0, 0.0)), Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(triangle.closest_point(Vector3::new(3.0, -1.0, 0.0)), Vector3::new(2.0, 0.0, 0.0));
+    /// ```
+    pub fn closest_point(self, point: Vector3) -> Vector3 {
+        let Triangle { a, b, c } = self;
+
+        //Real-Time Collision Detection, Christer Ericson, 5.1.5
+        let ab = b - a;
+        let ac = c - a;
+
+        let ap = point - a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return a;
+        }
+
+        let bp = point - b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= 0.0 && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= 0.0 && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denominator = va + vb + vc;
+        a + ab * (vb / denominator) + ac * (vc / denominator)
+    }
+}
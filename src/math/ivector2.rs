@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use super::{IVector3, Vector2};
+
+/// Two component vector of signed integers, used for grid and pixel coordinates that can be negative.
+///
+/// Has the same operators as `Vector3`, `*` between two vectors is the dot product and `/` by a scalar rounds towards
+/// zero like integer division does.
+///
+/// # Examples
+/// ```
+/// use copper_engine::math::{IVector2, Vector2};
+///
+/// let cell = IVector2::new(-3, 4) * 2;
+///
+/// assert_eq!(cell.abs(), IVector2::new(6, 8));
+/// assert_eq!(cell.clamp(IVector2::new(-4, -4), IVector2::new(4, 4)), IVector2::new(-4, 4));
+/// assert_eq!(Vector2::from(cell), Vector2::new(-6.0, 8.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "[i32; 2]", into = "[i32; 2]")]
+pub struct IVector2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl IVector2 {
+    pub fn new(x: i32, y: i32) -> IVector2 {
+        IVector2 { x, y }
+    }
+
+    /// Vector with `z` added as third component.
+    pub fn extend(self, z: i32) -> IVector3 {
+        IVector3::new(self.x, self.y, z)
+    }
+
+    /// Components rounded towards zero.
+    pub fn from_vector2(vector: Vector2) -> IVector2 {
+        IVector2::new(vector.x as i32, vector.y as i32)
+    }
+}
+
+impl_vector!(IVector2, i32, 2, x: 0, y: 1);
+impl_signed_vector!(IVector2, x, y);
+impl_component_bounds!(IVector2, x, y);
+impl_swizzles!(IVector2 => IVector2 { yx: y x });
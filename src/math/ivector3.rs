@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use super::{IVector2, Vector3};
+
+/// Three component vector of signed integers, used for voxel and grid coordinates that can be negative.
+///
+/// Has the same operators as `Vector3`, `*` between two vectors is the dot product and `/` by a scalar rounds towards
+/// zero like integer division does.
+///
+/// # Examples
+/// ```
+/// use copper_engine::math::{IVector2, IVector3, Vector3};
+///
+/// let voxel = IVector3::from([1, -2, 3]) + IVector3::new(1, 1, 1);
+///
+/// assert_eq!(voxel, IVector3::new(2, -1, 4));
+/// assert_eq!(voxel.xz(), IVector2::new(2, 4));
+/// assert_eq!(Vector3::from(voxel).to_array(), [2.0, -1.0, 4.0]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "[i32; 3]", into = "[i32; 3]")]
+pub struct IVector3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl IVector3 {
+    pub fn new(x: i32, y: i32, z: i32) -> IVector3 {
+        IVector3 { x, y, z }
+    }
+
+    /// Components rounded towards zero.
+    pub fn from_vector3(vector: Vector3) -> IVector3 {
+        IVector3::new(vector.x as i32, vector.y as i32, vector.z as i32)
+    }
+}
+
+impl_vector!(IVector3, i32, 3, x: 0, y: 1, z: 2);
+impl_signed_vector!(IVector3, x, y, z);
+impl_component_bounds!(IVector3, x, y, z);
+impl_swizzles!(IVector3 => IVector2 { xy: x y, xz: x z, yz: y z });
+impl_swizzles!(IVector3 => IVector3 { zyx: z y x });
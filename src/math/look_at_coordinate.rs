@@ -1,5 +1,27 @@
-use super::{EulerAngle, Vector3};
+//! Look at coordinate is a point in world space something looks at, like `Camera::rotation`.
 
+use super::{EulerAngle, Quaternion, Vector3};
+
+/// # Fields
+/// - x: Position of the target point on the x-axis.
+/// - y: Position of the target point on the y-axis.
+/// - z: Position of the target point on the z-axis.
+///
+/// Coordinates created from a rotation are one unit away from the offset they are created for, in the direction the
+/// rotation turns the forward direction -z into. See `EulerAngle` for the axis convention.
+///
+/// Usage of `::new()` is strongly recommended!
+///
+/// # Examples
+/// ```
+/// use copper_engine::math::{EulerAngle, LookAtCoordinate, Vector3};
+///
+/// let camera_position = Vector3::new(0.0, 1.0, 0.0);
+/// let target = LookAtCoordinate::from_euler_angle(EulerAngle::new(0.0, 0.0, 0.0), camera_position);
+///
+/// assert!((target.to_vector3() - Vector3::new(0.0, 1.0, -1.0)).get_length() < 1e-6);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct LookAtCoordinate {
     pub x: f32,
     pub y: f32,
@@ -7,13 +29,57 @@ pub struct LookAtCoordinate {
 }
 
 impl LookAtCoordinate {
-    fn new(x: f32, y: f32, z: f32) -> LookAtCoordinate {
+    pub fn new(x: f32, y: f32, z: f32) -> LookAtCoordinate {
         LookAtCoordinate { x, y, z }
     }
 
-    fn from_euler_angle(rotation: EulerAngle, offset: Vector3) -> LookAtCoordinate {
-        
-        todo!()
+    /// Point one unit from `offset` in the direction of `direction`. Zero direction returns `offset`.
+    pub fn from_direction(direction: Vector3, offset: Vector3) -> LookAtCoordinate {
+        LookAtCoordinate::from(offset + direction.normalize())
+    }
+
+    /// Point one unit from `offset` in the direction given by the yaw and pitch of `rotation`. The roll is ignored.
+    pub fn from_euler_angle(rotation: EulerAngle, offset: Vector3) -> LookAtCoordinate {
+        LookAtCoordinate::from_direction(rotation.to_direction(), offset)
+    }
+
+    /// Point one unit from `offset` in the forward direction, -z, turned by `rotation`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::FRAC_PI_2;
+    /// use copper_engine::math::{LookAtCoordinate, Quaternion, Vector3};
+    ///
+    /// let turn_right = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), -FRAC_PI_2);
+    /// let target = LookAtCoordinate::from_quaternion(turn_right, Vector3::new(0.0, 0.0, 0.0));
+    ///
+    /// assert!((target.to_vector3() - Vector3::new(1.0, 0.0, 0.0)).get_length() < 1e-6);
+    /// ```
+    pub fn from_quaternion(rotation: Quaternion, offset: Vector3) -> LookAtCoordinate {
+        LookAtCoordinate::from_direction(
+            rotation.rotate_vector(Vector3::new(0.0, 0.0, -1.0)),
+            offset,
+        )
+    }
+
+    /// Normalized direction from `offset` to the point, zero if the point is at `offset`.
+    pub fn direction_from(self, offset: Vector3) -> Vector3 {
+        (self.to_vector3() - offset).normalize()
+    }
+
+    pub fn to_vector3(self) -> Vector3 {
+        Vector3::new(self.x, self.y, self.z)
     }
 }
 
+impl From<Vector3> for LookAtCoordinate {
+    fn from(point: Vector3) -> Self {
+        LookAtCoordinate::new(point.x, point.y, point.z)
+    }
+}
+
+impl From<LookAtCoordinate> for Vector3 {
+    fn from(point: LookAtCoordinate) -> Self {
+        point.to_vector3()
+    }
+}
@@ -1,5 +1,56 @@
+//! 4x4 matrix for transforms, views and projections.
+//!
+//! Matrices use **row vectors**: a point is transformed with `point * matrix`, and `matrix[3]` holds the
+//! translation. `a * b` applies `a` first and then `b`, so a point is moved from model space to clip space with
+//! `point * model * view * projection`. Stored this way, `matrix` has the same memory layout as the column-major
+//! matrices of OpenGL and is uploaded to the shaders as is, where GLSL multiplies in the opposite order:
+//! `projection * view * model * point`.
+//!
+//! `Transform` composes in the same order as matrices. `Quaternion` composes the other way around, `a * b` applies
+//! `b` first, so `Matrix4x4::from_rotation(a * b)` equals `Matrix4x4::from_rotation(b) * Matrix4x4::from_rotation(a)`.
+
 use std::ops;
 
+use super::{Quaternion, Vector3, Vector4};
+
+/// # Fields
+/// - matrix: Rows of the matrix, see the module documentation for the convention.
+///
+/// # Examples
+/// ```
+/// use copper_engine::math::{Matrix4x4, Quaternion, Vector3};
+///
+/// let rotate = Matrix4x4::from_rotation(Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 1.0));
+/// let translate = Matrix4x4::from_translation(Vector3::new(0.0, 5.0, 0.0));
+/// let matrix = rotate * translate;
+///
+/// // Turned first, then moved.
+/// let point = Vector3::new(0.0, 0.0, 2.0) * matrix;
+/// assert!((point - Vector3::new(0.0, 5.0, 2.0)).get_length() < 1e-6);
+/// ```
+///
+/// Vectors are multiplied from the left only, as `vector * matrix`. There is no `matrix * vector`: with row vectors it
+/// would have to mean `vector * matrix.transpose()`, which is not the transform of the matrix. Unprojecting a point
+/// from normalized device coordinates back into the world multiplies it with the inverse view projection matrix:
+/// ```
+/// use std::f32::consts::FRAC_PI_2;
+/// use copper_engine::math::{Matrix4x4, Vector3, Vector4};
+///
+/// let view = Matrix4x4::look_at(
+///     Vector3::new(0.0, 0.0, 5.0),
+///     Vector3::new(0.0, 0.0, 0.0),
+///     Vector3::new(0.0, 1.0, 0.0),
+/// );
+/// let view_projection = view * Matrix4x4::perspective(FRAC_PI_2, 1.0, 0.1, 100.0);
+///
+/// let world = Vector3::new(1.0, 2.0, -3.0);
+/// let clip = Vector4::from_vector3(world, 1.0) * view_projection;
+/// let ndc = clip.to_point();
+///
+/// // `Vector3 * Matrix4x4` divides by w, like `to_point()`.
+/// let unprojected = ndc * view_projection.inverse().unwrap();
+/// assert!(unprojected.approx_eq(world, 1e-4));
+/// ```
 #[derive(Clone, Copy, Debug)]
 pub struct Matrix4x4 {
     pub matrix: [[f32; 4]; 4],
@@ -34,6 +85,119 @@ impl Matrix4x4 {
         )
     }
 
+    /// creates matrix moving points by `translation`.
+    pub fn from_translation(translation: Vector3) -> Matrix4x4 {
+        let mut matrix = Matrix4x4::identity();
+        matrix.matrix[3] = [translation.x, translation.y, translation.z, 1.0];
+
+        matrix
+    }
+
+    /// creates matrix scaling points on each axis.
+    pub fn from_scale(scale: Vector3) -> Matrix4x4 {
+        Matrix4x4::new(
+            [scale.x, 0.0, 0.0, 0.0],
+            [0.0, scale.y, 0.0, 0.0],
+            [0.0, 0.0, scale.z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        )
+    }
+
+    /// creates matrix rotating points around the origin, same as `Quaternion::to_matrix()`.
+    pub fn from_rotation(rotation: Quaternion) -> Matrix4x4 {
+        rotation.to_matrix()
+    }
+
+    /// creates right-handed perspective projection for OpenGL clip space. `fov_y` is the vertical field of view in
+    /// radian and `aspect_ratio` the width divided by the height of the view. Points between `z_near` and `z_far` in
+    /// front of the camera, along -z, end up at depth -1 to 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Matrix4x4, Vector3};
+    ///
+    /// let projection = Matrix4x4::perspective(1.0, 16.0 / 9.0, 0.1, 100.0);
+    ///
+    /// assert!(((Vector3::new(0.0, 0.0, -0.1) * projection).z + 1.0).abs() < 1e-5);
+    /// assert!(((Vector3::new(0.0, 0.0, -100.0) * projection).z - 1.0).abs() < 1e-5);
+    /// ```
+    pub fn perspective(fov_y: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Matrix4x4 {
+        let f = 1.0 / (fov_y / 2.0).tan();
+        let depth = z_near - z_far;
+
+        Matrix4x4::new(
+            [f / aspect_ratio, 0.0, 0.0, 0.0],
+            [0.0, f, 0.0, 0.0],
+            [0.0, 0.0, (z_far + z_near) / depth, -1.0],
+            [0.0, 0.0, 2.0 * z_far * z_near / depth, 0.0],
+        )
+    }
+
+    /// creates right-handed orthographic projection for OpenGL clip space. The box from `left`, `bottom` and
+    /// `-z_near` to `right`, `top` and `-z_far` is mapped to the cube from -1 to 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Matrix4x4, Vector3};
+    ///
+    /// let projection = Matrix4x4::orthographic(0.0, 800.0, 0.0, 600.0, 0.0, 10.0);
+    /// let corner = Vector3::new(800.0, 600.0, -10.0) * projection;
+    ///
+    /// assert!((corner - Vector3::new(1.0, 1.0, 1.0)).get_length() < 1e-6);
+    /// ```
+    pub fn orthographic(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        z_near: f32,
+        z_far: f32,
+    ) -> Matrix4x4 {
+        let width = right - left;
+        let height = top - bottom;
+        let depth = z_far - z_near;
+
+        Matrix4x4::new(
+            [2.0 / width, 0.0, 0.0, 0.0],
+            [0.0, 2.0 / height, 0.0, 0.0],
+            [0.0, 0.0, -2.0 / depth, 0.0],
+            [
+                -(right + left) / width,
+                -(top + bottom) / height,
+                -(z_far + z_near) / depth,
+                1.0,
+            ],
+        )
+    }
+
+    /// creates right-handed view matrix of a camera at `eye` looking at `target`, with `up` pointing as close to up
+    /// on the screen as possible. Moves `eye` to the origin and turns `target` onto the -z axis.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Matrix4x4, Vector3};
+    ///
+    /// let view = Matrix4x4::look_at(
+    ///     Vector3::new(10.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    ///
+    /// assert!((Vector3::new(0.0, 0.0, 0.0) * view - Vector3::new(0.0, 0.0, -10.0)).get_length() < 1e-6);
+    /// ```
+    pub fn look_at(eye: Vector3, target: Vector3, up: Vector3) -> Matrix4x4 {
+        let forward = (target - eye).normalize();
+        let right = forward.cross_product(up).normalize();
+        let up = right.cross_product(forward);
+
+        Matrix4x4::new(
+            [right.x, up.x, -forward.x, 0.0],
+            [right.y, up.y, -forward.y, 0.0],
+            [right.z, up.z, -forward.z, 0.0],
+            [-right.dot(eye), -up.dot(eye), forward.dot(eye), 1.0],
+        )
+    }
+
     pub fn transpose(self) -> Matrix4x4 {
         let mut result = Matrix4x4::empty();
 
@@ -64,8 +228,125 @@ impl Matrix4x4 {
 
         self.matrix[row][col] = value;
     }
+
+    /// 2x2 sub-determinants of the upper two and lower two rows, shared by `determinant()` and `inverse()`.
+    fn sub_determinants(self) -> ([f32; 6], [f32; 6]) {
+        //https://www.geometrictools.com/Documentation/LaplaceExpansionTheorem.pdf
+        let m = self.matrix;
+
+        let upper = [
+            m[0][0] * m[1][1] - m[1][0] * m[0][1],
+            m[0][0] * m[1][2] - m[1][0] * m[0][2],
+            m[0][0] * m[1][3] - m[1][0] * m[0][3],
+            m[0][1] * m[1][2] - m[1][1] * m[0][2],
+            m[0][1] * m[1][3] - m[1][1] * m[0][3],
+            m[0][2] * m[1][3] - m[1][2] * m[0][3],
+        ];
+        let lower = [
+            m[2][0] * m[3][1] - m[3][0] * m[2][1],
+            m[2][0] * m[3][2] - m[3][0] * m[2][2],
+            m[2][0] * m[3][3] - m[3][0] * m[2][3],
+            m[2][1] * m[3][2] - m[3][1] * m[2][2],
+            m[2][1] * m[3][3] - m[3][1] * m[2][3],
+            m[2][2] * m[3][3] - m[3][2] * m[2][3],
+        ];
+
+        (upper, lower)
+    }
+
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Matrix4x4, Vector3};
+    ///
+    /// let matrix = Matrix4x4::from_scale(Vector3::new(2.0, 3.0, 4.0));
+    /// assert_eq!(matrix.determinant(), 24.0);
+    /// ```
+    pub fn determinant(self) -> f32 {
+        let (s, c) = self.sub_determinants();
+
+        determinant_from(&s, &c)
+    }
+
+    /// Matrix that undoes this one, `None` if the matrix can not be inverted because its determinant is 0.
+    ///
+    /// Inverting the view and projection gives the matrix that unprojects points from clip space back into the
+    /// world, for example to find what the mouse points at.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Matrix4x4, Vector3};
+    ///
+    /// let view = Matrix4x4::look_at(
+    ///     Vector3::new(0.0, 2.0, 10.0),
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// let view_projection = view * Matrix4x4::perspective(1.0, 4.0 / 3.0, 0.1, 100.0);
+    ///
+    /// let point = Vector3::new(1.0, -2.0, 3.0);
+    /// let clip = point * view_projection;
+    /// let unprojected = clip * view_projection.inverse().unwrap();
+    ///
+    /// assert!((unprojected - point).get_length() < 1e-3);
+    /// assert!(Matrix4x4::empty().inverse().is_none());
+    /// ```
+    pub fn inverse(self) -> Option<Matrix4x4> {
+        let (s, c) = self.sub_determinants();
+        let determinant = determinant_from(&s, &c);
+
+        if determinant == 0.0 {
+            return None;
+        }
+
+        let m = self.matrix;
+        let inverse = Matrix4x4::new(
+            [
+                m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3],
+                -m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3],
+                m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3],
+                -m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3],
+            ],
+            [
+                -m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1],
+                m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1],
+                -m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1],
+                m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1],
+            ],
+            [
+                m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0],
+                -m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0],
+                m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0],
+                -m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0],
+            ],
+            [
+                -m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0],
+                m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0],
+                -m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0],
+                m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0],
+            ],
+        );
+
+        Some(inverse * (1.0 / determinant))
+    }
+
+    /// Transforms point, with a `w` of `1.0`, and divides the result by its `w`. Same as `point * matrix`.
+    pub fn transform_point(self, point: Vector3) -> Vector3 {
+        (Vector4::from_vector3(point, 1.0) * self).to_point()
+    }
+
+    /// Transforms direction, with a `w` of `0.0`, so that the translation of the matrix does not affect it.
+    pub fn transform_vector(self, vector: Vector3) -> Vector3 {
+        (Vector4::from_vector3(vector, 0.0) * self).xyz()
+    }
+}
+
+/// Determinant from the sub-determinants returned by `Matrix4x4::sub_determinants()`.
+fn determinant_from(s: &[f32; 6], c: &[f32; 6]) -> f32 {
+    s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
 }
 
+/// Combines two matrices, `a * b` applies `a` first and then `b`. Opposite to `Quaternion`, see the module
+/// documentation.
 impl ops::Mul<Matrix4x4> for Matrix4x4 {
     type Output = Matrix4x4;
 
@@ -89,3 +370,33 @@ impl ops::Mul<Matrix4x4> for Matrix4x4 {
         Matrix4x4::from_array(output)
     }
 }
+
+impl ops::Mul<f32> for Matrix4x4 {
+    type Output = Matrix4x4;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Matrix4x4::from_array(self.matrix.map(|row| row.map(|value| value * rhs)))
+    }
+}
+
+/// Transforms homogeneous coordinates as a row vector.
+impl ops::Mul<Matrix4x4> for Vector4 {
+    type Output = Vector4;
+
+    fn mul(self, rhs: Matrix4x4) -> Self::Output {
+        let m = rhs.matrix;
+        let column =
+            |j: usize| self.x * m[0][j] + self.y * m[1][j] + self.z * m[2][j] + self.w * m[3][j];
+
+        Vector4::new(column(0), column(1), column(2), column(3))
+    }
+}
+
+/// Transforms point, see `Matrix4x4::transform_point()`.
+impl ops::Mul<Matrix4x4> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Matrix4x4) -> Self::Output {
+        rhs.transform_point(self)
+    }
+}
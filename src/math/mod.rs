@@ -1,11 +1,28 @@
+#[macro_use]
+mod vector_macros;
+
 pub mod quaternion;
 pub mod euler_angle;
 pub mod look_at_coordinate;
+pub mod vector2;
 pub mod vector3;
+pub mod vector4;
+pub mod ivector2;
+pub mod ivector3;
+pub mod uvector2;
 pub mod matrix4x4;
+pub mod transform;
+pub mod geometry;
 
 pub use quaternion::Quaternion;
 pub use euler_angle::EulerAngle;
 pub use look_at_coordinate::LookAtCoordinate;
+pub use vector2::Vector2;
 pub use vector3::Vector3;
-pub use matrix4x4::Matrix4x4;
\ No newline at end of file
+pub use vector4::Vector4;
+pub use ivector2::IVector2;
+pub use ivector3::IVector3;
+pub use uvector2::UVector2;
+pub use matrix4x4::Matrix4x4;
+pub use transform::Transform;
+pub use geometry::{Aabb, Frustum, Obb, Plane, Ray, Sphere, Triangle};
\ No newline at end of file
@@ -1,3 +1,44 @@
+//! Quaternion describes a rotation. Rotations are combined with `*` and applied to vectors with `rotate_vector()`.
+//!
+//! **Composition order:** `a * b` applies `b` first and then `a`, like the Hamilton product in the math literature.
+//! This is the opposite of `Matrix4x4` and `Transform`, where `a * b` applies `a` first. The world rotation of a
+//! child is `parent_rotation * child_rotation`, while its world matrix is `child_matrix * parent_matrix`:
+//!
+//! ```
+//! use copper_engine::math::{Matrix4x4, Quaternion, Vector3};
+//!
+//! let parent = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 1.0);
+//! let child = Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.5);
+//! let point = Vector3::new(1.0, 2.0, 3.0);
+//!
+//! let by_quaternion = (parent * child).rotate_vector(point);
+//! let by_matrix = (Matrix4x4::from_rotation(child) * Matrix4x4::from_rotation(parent)).transform_point(point);
+//!
+//! assert!(by_quaternion.approx_eq(by_matrix, 1e-5));
+//! ```
+
+use std::{f32::consts::PI, ops};
+
+use super::{EulerAngle, Matrix4x4, Vector3};
+
+/// Dot product of two unit quaternions above which they are treated as the same rotation by `slerp()`.
+const SLERP_THRESHOLD: f32 = 0.9995;
+
+/// Rotation stored as unit quaternion. Quaternions that are not normalized scale the vectors they rotate.
+///
+/// # Examples
+/// ```
+/// use std::f32::consts::FRAC_PI_2;
+/// use copper_engine::math::{Quaternion, Vector3};
+///
+/// let yaw = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), FRAC_PI_2);
+/// let pitch = Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), FRAC_PI_2);
+///
+/// // Pitch is applied first, then yaw.
+/// let rotated = (yaw * pitch).rotate_vector(Vector3::new(0.0, 1.0, 0.0));
+/// assert!((rotated - Vector3::new(1.0, 0.0, 0.0)).get_length() < 1e-6);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Quaternion {
     pub x: f32,
     pub y: f32,
@@ -10,20 +51,414 @@ impl Quaternion {
         Quaternion { x, y, z, w }
     }
 
+    pub fn identity() -> Quaternion {
+        Quaternion::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// creates rotation from yaw around the y-axis, pitch around the x-axis and roll around the z-axis in radian.
+    /// See `EulerAngle` for the convention.
     pub fn from_euler_separate(yaw: f32, pitch: f32, roll: f32) -> Quaternion {
-        //https://stackoverflow.com/a/70462919
-        let cy = (yaw * 0.5).cos();
-        let sy = (yaw * 0.5).sin();
-        let cp = (pitch * 0.5).cos();
-        let sp = (pitch * 0.5).sin();
-        let cr = (roll * 0.5).cos();
-        let sr = (roll * 0.5).sin();
-
-        Quaternion {
-            w: (cr * cp * cy + sr * sp * sy),
-            x: (sr * cp * cy - cr * sp * sy),
-            y: (cr * sp * cy + sr * cp * sy),
-            z: (cr * cp * sy - sr * sp * cy),
+        EulerAngle::new(yaw, pitch, roll).to_quaternion()
+    }
+
+    /// creates rotation from euler angles, same as `EulerAngle::to_quaternion()`.
+    pub fn from_euler(rotation: EulerAngle) -> Quaternion {
+        rotation.to_quaternion()
+    }
+
+    /// Rotation as euler angles, same as `EulerAngle::from_quaternion()`.
+    pub fn to_euler(self) -> EulerAngle {
+        EulerAngle::from_quaternion(self)
+    }
+
+    /// creates rotation of `angle` radians counter-clockwise around `axis`. The axis does not have to be normalized.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::FRAC_PI_2;
+    /// use copper_engine::math::{Quaternion, Vector3};
+    ///
+    /// let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+    /// let rotated = rotation.rotate_vector(Vector3::new(1.0, 0.0, 0.0));
+    ///
+    /// assert!((rotation.z - 0.5f32.sqrt()).abs() < 1e-6);
+    /// assert!((rotation.w - 0.5f32.sqrt()).abs() < 1e-6);
+    /// assert!((rotated - Vector3::new(0.0, 1.0, 0.0)).get_length() < 1e-6);
+    /// ```
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Quaternion {
+        let axis = axis.normalize();
+        let (sin, cos) = (angle * 0.5).sin_cos();
+
+        Quaternion::new(axis.x * sin, axis.y * sin, axis.z * sin, cos)
+    }
+
+    /// Axis and angle in radians of the rotation, the reverse of `from_axis_angle()`. The angle is in range 0-2π. The
+    /// identity rotation returns the x-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Quaternion, Vector3};
+    ///
+    /// let (axis, angle) = Quaternion::from_axis_angle(Vector3::new(0.0, 2.0, 0.0), 0.5).to_axis_angle();
+    ///
+    /// assert!((axis - Vector3::new(0.0, 1.0, 0.0)).get_length() < 1e-6);
+    /// assert!((angle - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn to_axis_angle(self) -> (Vector3, f32) {
+        let quaternion = self.normalize();
+        let axis = Vector3::new(quaternion.x, quaternion.y, quaternion.z);
+        let sin = axis.get_length();
+
+        if sin < f32::EPSILON {
+            return (Vector3::new(1.0, 0.0, 0.0), 0.0);
+        }
+
+        (axis / sin, 2.0 * sin.atan2(quaternion.w))
+    }
+
+    /// creates the shortest rotation that turns direction `from` into direction `to`. The directions do not have to
+    /// be normalized. Opposite directions are turned half around an axis perpendicular to `from`.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Quaternion, Vector3};
+    ///
+    /// let from = Vector3::new(1.0, 0.0, 0.0);
+    /// let to = Vector3::new(0.0, 0.0, -3.0);
+    /// let rotation = Quaternion::from_rotation_arc(from, to);
+    ///
+    /// assert!((rotation.rotate_vector(from) - Vector3::new(0.0, 0.0, -1.0)).get_length() < 1e-6);
+    ///
+    /// let opposite = Quaternion::from_rotation_arc(from, -from);
+    /// assert!((opposite.rotate_vector(from) + from).get_length() < 1e-6);
+    /// ```
+    pub fn from_rotation_arc(from: Vector3, to: Vector3) -> Quaternion {
+        //https://stackoverflow.com/a/11741520
+        let from = from.normalize();
+        let to = to.normalize();
+        let cos = from.dot(to);
+
+        if cos < -1.0 + 1e-6 {
+            let axis = if from.x.abs() < 0.9 {
+                Vector3::new(1.0, 0.0, 0.0)
+            } else {
+                Vector3::new(0.0, 1.0, 0.0)
+            };
+
+            return Quaternion::from_axis_angle(from.cross_product(axis), PI);
         }
+
+        let axis = from.cross_product(to);
+
+        Quaternion::new(axis.x, axis.y, axis.z, 1.0 + cos).normalize()
+    }
+
+    /// creates rotation that turns the -z axis, the forward direction of objects, into `forward` and the y-axis as
+    /// close to `up` as possible. If `forward` is parallel to `up`, the shortest rotation to `forward` is returned.
+    /// Zero `forward` returns the identity rotation.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Quaternion, Vector3};
+    ///
+    /// let up = Vector3::new(0.0, 1.0, 0.0);
+    /// let rotation = Quaternion::look_rotation(Vector3::new(1.0, 0.0, 0.0), up);
+    ///
+    /// let forward = rotation.rotate_vector(Vector3::new(0.0, 0.0, -1.0));
+    /// assert!((forward - Vector3::new(1.0, 0.0, 0.0)).get_length() < 1e-6);
+    /// assert!((rotation.rotate_vector(up) - up).get_length() < 1e-6);
+    /// ```
+    pub fn look_rotation(forward: Vector3, up: Vector3) -> Quaternion {
+        let forward_axis = Vector3::new(0.0, 0.0, -1.0);
+
+        if forward.get_length() < f32::EPSILON {
+            return Quaternion::identity();
+        }
+
+        let back = -forward.normalize();
+        let right = up.cross_product(back);
+
+        if right.get_length() < f32::EPSILON {
+            return Quaternion::from_rotation_arc(forward_axis, forward);
+        }
+
+        let right = right.normalize();
+        let up = back.cross_product(right);
+
+        Quaternion::from_basis(right, up, back)
+    }
+
+    /// creates rotation that turns the x, y and z axes into `x_axis`, `y_axis` and `z_axis`. The axes have to be
+    /// orthonormal and right-handed.
+    pub fn from_basis(x_axis: Vector3, y_axis: Vector3, z_axis: Vector3) -> Quaternion {
+        //https://www.euclideanspace.com/maths/geometry/rotations/conversions/matrixToQuaternion/
+        let trace = x_axis.x + y_axis.y + z_axis.z;
+
+        let quaternion = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quaternion::new(
+                (y_axis.z - z_axis.y) / s,
+                (z_axis.x - x_axis.z) / s,
+                (x_axis.y - y_axis.x) / s,
+                0.25 * s,
+            )
+        } else if x_axis.x > y_axis.y && x_axis.x > z_axis.z {
+            let s = (1.0 + x_axis.x - y_axis.y - z_axis.z).sqrt() * 2.0;
+            Quaternion::new(
+                0.25 * s,
+                (y_axis.x + x_axis.y) / s,
+                (z_axis.x + x_axis.z) / s,
+                (y_axis.z - z_axis.y) / s,
+            )
+        } else if y_axis.y > z_axis.z {
+            let s = (1.0 + y_axis.y - x_axis.x - z_axis.z).sqrt() * 2.0;
+            Quaternion::new(
+                (y_axis.x + x_axis.y) / s,
+                0.25 * s,
+                (z_axis.y + y_axis.z) / s,
+                (z_axis.x - x_axis.z) / s,
+            )
+        } else {
+            let s = (1.0 + z_axis.z - x_axis.x - y_axis.y).sqrt() * 2.0;
+            Quaternion::new(
+                (z_axis.x + x_axis.z) / s,
+                (z_axis.y + y_axis.z) / s,
+                0.25 * s,
+                (x_axis.y - y_axis.x) / s,
+            )
+        };
+
+        quaternion.normalize()
+    }
+
+    /// Dot product of the quaternions as 4d vectors. For unit quaternions, `1.0` or `-1.0` means both describe the
+    /// same rotation.
+    pub fn dot(self, other: Quaternion) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    pub fn length(self) -> f32 {
+        (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt()
+    }
+
+    pub fn normalize(self) -> Quaternion {
+        let length = self.length();
+
+        Quaternion::new(
+            self.x / length,
+            self.y / length,
+            self.z / length,
+            self.w / length,
+        )
+    }
+
+    /// Reverses the rotation of a unit quaternion.
+    pub fn conjugate(self) -> Quaternion {
+        Quaternion::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Reverses the rotation, also for quaternions that are not normalized. Same as `conjugate()` for unit
+    /// quaternions.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Quaternion, Vector3};
+    ///
+    /// let rotation = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    /// let identity = rotation * rotation.inverse();
+    ///
+    /// assert!((identity.w - 1.0).abs() < 1e-6);
+    /// assert!(Vector3::new(identity.x, identity.y, identity.z).get_length() < 1e-6);
+    /// ```
+    pub fn inverse(self) -> Quaternion {
+        let length_squared = self.dot(self);
+        let conjugate = self.conjugate();
+
+        Quaternion::new(
+            conjugate.x / length_squared,
+            conjugate.y / length_squared,
+            conjugate.z / length_squared,
+            conjugate.w / length_squared,
+        )
+    }
+
+    /// Smallest angle in radians of the rotation turning `self` into `other`, in range 0-π.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::FRAC_PI_2;
+    /// use copper_engine::math::{Quaternion, Vector3};
+    ///
+    /// let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), FRAC_PI_2);
+    ///
+    /// assert!((Quaternion::identity().angle_between(rotation) - FRAC_PI_2).abs() < 1e-6);
+    /// ```
+    pub fn angle_between(self, other: Quaternion) -> f32 {
+        let cos = self.normalize().dot(other.normalize()).abs().min(1.0);
+
+        2.0 * cos.acos()
+    }
+
+    /// Linear blend between two rotations along the shortest path, normalized afterwards. Faster than `slerp()`, but
+    /// the rotation speed is not constant. `t` of `0.0` returns `self` and `1.0` returns `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::FRAC_PI_2;
+    /// use copper_engine::math::{Quaternion, Vector3};
+    ///
+    /// let end = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), FRAC_PI_2);
+    /// let half = Quaternion::identity().nlerp(end, 0.5);
+    ///
+    /// let expected = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), FRAC_PI_2 / 2.0);
+    /// assert!(half.angle_between(expected) < 1e-3);
+    /// ```
+    pub fn nlerp(self, other: Quaternion, t: f32) -> Quaternion {
+        let other = if self.dot(other) < 0.0 { -other } else { other };
+
+        Quaternion::new(
+            self.x + (other.x - self.x) * t,
+            self.y + (other.y - self.y) * t,
+            self.z + (other.z - self.z) * t,
+            self.w + (other.w - self.w) * t,
+        )
+        .normalize()
+    }
+
+    /// Spherical blend between two unit rotations along the shortest path at constant rotation speed. `t` of `0.0`
+    /// returns `self` and `1.0` returns `other`. Used to smoothly turn towards a target rotation or blend
+    /// animations.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use copper_engine::math::{Quaternion, Vector3};
+    ///
+    /// let axis = Vector3::new(0.0, 0.0, 1.0);
+    /// let end = Quaternion::from_axis_angle(axis, PI * 0.75);
+    /// let third = Quaternion::identity().slerp(end, 1.0 / 3.0);
+    ///
+    /// assert!(third.angle_between(Quaternion::from_axis_angle(axis, PI * 0.25)) < 1e-3);
+    /// ```
+    pub fn slerp(self, other: Quaternion, t: f32) -> Quaternion {
+        //https://en.wikipedia.org/wiki/Slerp#Source_code
+        let mut cos = self.dot(other);
+        let other = if cos < 0.0 {
+            cos = -cos;
+            -other
+        } else {
+            other
+        };
+
+        if cos > SLERP_THRESHOLD {
+            return self.nlerp(other, t);
+        }
+
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let from_weight = ((1.0 - t) * angle).sin() / sin;
+        let to_weight = (t * angle).sin() / sin;
+
+        Quaternion::new(
+            self.x * from_weight + other.x * to_weight,
+            self.y * from_weight + other.y * to_weight,
+            self.z * from_weight + other.z * to_weight,
+            self.w * from_weight + other.w * to_weight,
+        )
+    }
+
+    /// Rotates the vector. Same as `quaternion * vector`.
+    pub fn rotate_vector(self, vector: Vector3) -> Vector3 {
+        //https://fgiesen.wordpress.com/2019/02/09/rotating-a-single-vector-using-a-quaternion/
+        let axis = Vector3::new(self.x, self.y, self.z);
+        let t = axis.cross_product(vector) * 2.0;
+
+        vector + t * self.w + axis.cross_product(t)
+    }
+
+    /// Converts rotation into a matrix. Rows of the matrix are the rotated x, y and z axes like in the matrices
+    /// uploaded to the shaders.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::FRAC_PI_2;
+    /// use copper_engine::math::{Quaternion, Vector3};
+    ///
+    /// let matrix = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2).to_matrix().matrix;
+    ///
+    /// // The x-axis is turned into the y-axis.
+    /// assert!(matrix[0][0].abs() < 1e-6 && (matrix[0][1] - 1.0).abs() < 1e-6);
+    /// // The y-axis is turned into the -x-axis.
+    /// assert!((matrix[1][0] + 1.0).abs() < 1e-6 && matrix[1][1].abs() < 1e-6);
+    /// ```
+    pub fn to_matrix(self) -> Matrix4x4 {
+        let Quaternion { x, y, z, w } = self;
+
+        Matrix4x4::new(
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y + w * z),
+                2.0 * (x * z - w * y),
+                0.0,
+            ],
+            [
+                2.0 * (x * y - w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z + w * x),
+                0.0,
+            ],
+            [
+                2.0 * (x * z + w * y),
+                2.0 * (y * z - w * x),
+                1.0 - 2.0 * (x * x + y * y),
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0],
+        )
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Quaternion::identity()
+    }
+}
+
+impl From<EulerAngle> for Quaternion {
+    fn from(rotation: EulerAngle) -> Self {
+        rotation.to_quaternion()
+    }
+}
+
+/// Combines two rotations, `a * b` applies `b` first and then `a`. Opposite to `Matrix4x4` and `Transform`, see the
+/// module documentation.
+impl ops::Mul<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Self::Output {
+        Quaternion::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+/// Negated quaternion, which describes the same rotation.
+impl ops::Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Self::Output {
+        Quaternion::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+/// Rotates the vector, see `rotate_vector()`.
+impl ops::Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Self::Output {
+        self.rotate_vector(rhs)
     }
 }
@@ -0,0 +1,209 @@
+//! Translation, rotation and scale of an object. The engine uses right-handed coordinates with y pointing up,
+//! objects look towards -z.
+//!
+//! Transforms are combined with `*` in the order of `Matrix4x4`: `a * b` applies `a` first and then `b`, so the world
+//! transform of a child is `child * parent`. Their rotations combine the other way, as `Quaternion` applies the
+//! right-hand side first: the rotation of `child * parent` is `parent.rotation * child.rotation`.
+
+use std::ops;
+
+use super::{Matrix4x4, Quaternion, Vector3};
+
+/// # Fields
+/// - translation: Offset from the origin of the parent space.
+/// - rotation: Rotation around the object's own origin.
+/// - scale: Scale on each local axis, applied before the rotation.
+#[derive(Clone, Copy, Debug)]
+pub struct Transform {
+    pub translation: Vector3,
+    pub rotation: Quaternion,
+    pub scale: Vector3,
+}
+
+impl Transform {
+    pub fn new(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Transform {
+        Transform {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    pub fn identity() -> Transform {
+        Transform::new(
+            Vector3::new(0.0, 0.0, 0.0),
+            Quaternion::identity(),
+            Vector3::new(1.0, 1.0, 1.0),
+        )
+    }
+
+    pub fn from_translation(translation: Vector3) -> Transform {
+        Transform {
+            translation,
+            ..Transform::identity()
+        }
+    }
+
+    /// Converts transform into a model matrix. Rows of the matrix are the scaled and rotated x, y and z axes and the
+    /// last row is the translation.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use copper_engine::math::{Quaternion, Transform, Vector3};
+    ///
+    /// let transform = Transform::new(
+    ///     Vector3::new(1.0, 2.0, 3.0),
+    ///     Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), PI / 2.0),
+    ///     Vector3::new(2.0, 1.0, 1.0),
+    /// );
+    /// let matrix = transform.to_matrix().matrix;
+    ///
+    /// // The x-axis is scaled by two and turned towards -z.
+    /// assert!(matrix[0][0].abs() < 1e-6 && (matrix[0][2] + 2.0).abs() < 1e-6);
+    /// assert_eq!(matrix[3], [1.0, 2.0, 3.0, 1.0]);
+    /// ```
+    pub fn to_matrix(self) -> Matrix4x4 {
+        let mut matrix = self.rotation.to_matrix().matrix;
+
+        for (row, scale) in matrix
+            .iter_mut()
+            .zip([self.scale.x, self.scale.y, self.scale.z])
+        {
+            for value in row.iter_mut().take(3) {
+                *value *= scale;
+            }
+        }
+        matrix[3] = [
+            self.translation.x,
+            self.translation.y,
+            self.translation.z,
+            1.0,
+        ];
+
+        Matrix4x4::from_array(matrix)
+    }
+
+    /// Moves point from the local space of the transform into the parent space.
+    pub fn transform_point(self, point: Vector3) -> Vector3 {
+        self.rotation.rotate_vector(point.component_mul(self.scale)) + self.translation
+    }
+
+    /// Rotates and scales direction from the local space of the transform into the parent space.
+    pub fn transform_vector(self, vector: Vector3) -> Vector3 {
+        self.rotation
+            .rotate_vector(vector.component_mul(self.scale))
+    }
+
+    /// Creates transform that undoes this one. Exact when the scale is uniform, a rotated non-uniform scale can not
+    /// be undone by a single transform.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Quaternion, Transform, Vector3};
+    ///
+    /// let transform = Transform::new(
+    ///     Vector3::new(4.0, -1.0, 2.0),
+    ///     Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0), 1.0),
+    ///     Vector3::new(2.0, 2.0, 2.0),
+    /// );
+    /// let point = Vector3::new(1.0, 2.0, 3.0);
+    /// let back = transform.inverse().transform_point(transform.transform_point(point));
+    ///
+    /// assert!((back - point).get_length() < 1e-5);
+    /// ```
+    pub fn inverse(self) -> Transform {
+        let rotation = self.rotation.conjugate();
+        let scale = Vector3::new(1.0 / self.scale.x, 1.0 / self.scale.y, 1.0 / self.scale.z);
+        let translation = -rotation
+            .rotate_vector(self.translation)
+            .component_mul(scale);
+
+        Transform::new(translation, rotation, scale)
+    }
+
+    /// Direction the object is looking at, the rotated -z axis.
+    pub fn forward(self) -> Vector3 {
+        self.rotation.rotate_vector(Vector3::new(0.0, 0.0, -1.0))
+    }
+
+    /// The rotated x-axis.
+    pub fn right(self) -> Vector3 {
+        self.rotation.rotate_vector(Vector3::new(1.0, 0.0, 0.0))
+    }
+
+    /// The rotated y-axis.
+    pub fn up(self) -> Vector3 {
+        self.rotation.rotate_vector(Vector3::new(0.0, 1.0, 0.0))
+    }
+
+    /// Rotates the object around its origin. `rotation` is applied after the current rotation.
+    pub fn rotate(&mut self, rotation: Quaternion) {
+        self.rotation = (rotation * self.rotation).normalize();
+    }
+
+    /// Turns the object so that `forward()` points at `target` and `up()` is as close to `up` as possible. Nothing
+    /// happens if the target is at the translation of the object or straight above or below it.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::{Transform, Vector3};
+    ///
+    /// let mut transform = Transform::from_translation(Vector3::new(0.0, 0.0, 5.0));
+    /// transform.look_at(Vector3::new(5.0, 0.0, 5.0), Vector3::new(0.0, 1.0, 0.0));
+    ///
+    /// assert!((transform.forward() - Vector3::new(1.0, 0.0, 0.0)).get_length() < 1e-6);
+    /// ```
+    pub fn look_at(&mut self, target: Vector3, up: Vector3) {
+        let forward = target - self.translation;
+
+        if forward.get_length() < f32::EPSILON
+            || up.cross_product(forward).get_length() < f32::EPSILON
+        {
+            return;
+        }
+
+        self.rotation = Quaternion::look_rotation(forward, up);
+    }
+
+    /// Rotates the object around `point`. Both the translation and the rotation of the object change.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f32::consts::PI;
+    /// use copper_engine::math::{Quaternion, Transform, Vector3};
+    ///
+    /// let mut transform = Transform::from_translation(Vector3::new(2.0, 0.0, 0.0));
+    /// transform.rotate_around(
+    ///     Vector3::new(1.0, 0.0, 0.0),
+    ///     Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), PI),
+    /// );
+    ///
+    /// assert!((transform.translation - Vector3::new(0.0, 0.0, 0.0)).get_length() < 1e-6);
+    /// ```
+    pub fn rotate_around(&mut self, point: Vector3, rotation: Quaternion) {
+        self.translation = point + rotation.rotate_vector(self.translation - point);
+        self.rotate(rotation);
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::identity()
+    }
+}
+
+/// Combines two transforms like their matrices, `a * b` applies `a` first and then `b`. The world transform of a
+/// child is `child * parent`. This is the opposite order of `Quaternion`, the rotation of the result is
+/// `b.rotation * a.rotation`. The scale of the result is exact only when `b` has a uniform scale.
+impl ops::Mul<Transform> for Transform {
+    type Output = Transform;
+
+    fn mul(self, rhs: Transform) -> Self::Output {
+        Transform::new(
+            rhs.transform_point(self.translation),
+            (rhs.rotation * self.rotation).normalize(),
+            self.scale.component_mul(rhs.scale),
+        )
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use super::IVector2;
+
+/// Two component vector of unsigned integers, used for sizes in pixels like `Camera::window_size`.
+///
+/// Has the same operators as `Vector3` except negation, `*` between two vectors is the dot product. Like `u32`,
+/// subtracting past zero panics in debug builds.
+///
+/// # Examples
+/// ```
+/// use copper_engine::math::UVector2;
+///
+/// let window_size = UVector2::new(1280, 720);
+///
+/// assert_eq!(window_size / 2, UVector2::new(640, 360));
+/// assert_eq!(window_size.to_array(), [1280, 720]);
+/// assert_eq!(window_size.aspect_ratio(), 1280.0 / 720.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "[u32; 2]", into = "[u32; 2]")]
+pub struct UVector2 {
+    pub x: u32,
+    pub y: u32,
+}
+
+impl UVector2 {
+    pub fn new(x: u32, y: u32) -> UVector2 {
+        UVector2 { x, y }
+    }
+
+    /// `x` divided by `y`, infinite or NaN if `y` is 0.
+    pub fn aspect_ratio(self) -> f32 {
+        self.x as f32 / self.y as f32
+    }
+}
+
+impl_vector!(UVector2, u32, 2, x: 0, y: 1);
+impl_component_bounds!(UVector2, x, y);
+impl_swizzles!(UVector2 => UVector2 { yx: y x });
+
+impl From<UVector2> for IVector2 {
+    fn from(vector: UVector2) -> Self {
+        IVector2::new(vector.x as i32, vector.y as i32)
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use super::{IVector2, UVector2, Vector3};
+
+/// Two component vector, used for positions on the screen like `InputManager::mouse_position`.
+///
+/// Has the same operators as `Vector3`, `*` between two vectors is the dot product.
+///
+/// # Examples
+/// ```
+/// use copper_engine::math::Vector2;
+///
+/// let position = Vector2::new(1.0, 2.0);
+/// let moved = position + Vector2::from([2.0, 2.0]);
+///
+/// assert_eq!(moved, Vector2::new(3.0, 4.0));
+/// assert_eq!(moved.yx(), Vector2::from((4.0, 3.0)));
+/// assert!(position.lerp(moved, 0.5).approx_eq(Vector2::new(2.0, 3.0), 1e-6));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "[f32; 2]", into = "[f32; 2]")]
+pub struct Vector2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Vector2 {
+    pub fn new(x: f32, y: f32) -> Vector2 {
+        Vector2 { x, y }
+    }
+
+    /// Vector with `z` added as third component.
+    pub fn extend(self, z: f32) -> Vector3 {
+        Vector3::new(self.x, self.y, z)
+    }
+
+    /// Vector turned by 90 degrees counter-clockwise.
+    pub fn perpendicular(self) -> Vector2 {
+        Vector2::new(-self.y, self.x)
+    }
+}
+
+impl_vector!(Vector2, f32, 2, x: 0, y: 1);
+impl_float_vector!(Vector2, x, y);
+impl_swizzles!(Vector2 => Vector2 { yx: y x });
+
+impl From<IVector2> for Vector2 {
+    fn from(vector: IVector2) -> Self {
+        Vector2::new(vector.x as f32, vector.y as f32)
+    }
+}
+
+impl From<UVector2> for Vector2 {
+    fn from(vector: UVector2) -> Self {
+        Vector2::new(vector.x as f32, vector.y as f32)
+    }
+}
@@ -1,6 +1,26 @@
-use std::ops;
-
-#[derive(Clone, Copy, Debug)]
+use serde::{Deserialize, Serialize};
+
+use super::{IVector3, Vector2, Vector4};
+
+/// Three component vector, used for positions, directions, scales and colors.
+///
+/// `*` between two vectors is the dot product, use `component_mul()` to multiply component by component.
+///
+/// # Examples
+/// ```
+/// use copper_engine::math::{Vector2, Vector3};
+///
+/// let velocity = Vector3::new(1.0, -1.0, 0.0);
+/// let up = Vector3::new(0.0, 1.0, 0.0);
+///
+/// assert_eq!(velocity.reflect(up), Vector3::new(1.0, 1.0, 0.0));
+/// assert_eq!(velocity.project_on(up * 2.0), Vector3::new(0.0, -1.0, 0.0));
+/// assert_eq!(velocity.xz(), Vector2::new(1.0, 0.0));
+/// assert_eq!(velocity / 2.0, Vector3::new(0.5, -0.5, 0.0));
+/// assert_eq!(<[f32; 3]>::from(velocity.abs()), [1.0, 1.0, 0.0]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "[f32; 3]", into = "[f32; 3]")]
 pub struct Vector3 {
     pub x: f32,
     pub y: f32,
@@ -12,26 +32,25 @@ impl Vector3 {
         Vector3 { x, y, z }
     }
 
-    pub fn to_array(self) -> [f32; 3] {
-        [self.x, self.y, self.z]
-    }
-
-    pub fn get_length(self) -> f32 {
-        // a^2 = b^2 + c^2 + d^2
-        (self.x.powi(2) + self.y.powi(2) + self.z.powi(2)).sqrt()
-    }
-
-    /// Panics if length of the vector is 0
-    pub fn normalize(self) -> Vector3 {
-        let length = self.get_length();
-
-        if length == 0.0 {
-            return self;
-        }
-
-        Vector3::new(self.x / length, self.y / length, self.z / length)
-    }
-
+    /// Vector with `w` added as fourth component.
+    pub fn extend(self, w: f32) -> Vector4 {
+        Vector4::from_vector3(self, w)
+    }
+
+    /// Vector perpendicular to both vectors, following the right-hand rule.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::math::Vector3;
+    ///
+    /// let x = Vector3::new(1.0, 0.0, 0.0);
+    /// let y = Vector3::new(0.0, 1.0, 0.0);
+    /// let z = Vector3::new(0.0, 0.0, 1.0);
+    ///
+    /// assert_eq!(x.cross_product(y), z);
+    /// assert_eq!(z.cross_product(x), y);
+    /// assert_eq!(y.cross_product(z), x);
+    /// ```
     pub fn cross_product(self, other: Vector3) -> Vector3 {
         let mat_i = [[self.y, self.z], [other.y, other.z]];
         let mat_j = [[self.x, self.z], [other.x, other.z]];
@@ -41,139 +60,21 @@ impl Vector3 {
         let det_j = mat_j[0][0] * mat_j[1][1] - mat_j[0][1] * mat_j[1][0];
         let det_k = mat_k[0][0] * mat_k[1][1] - mat_k[0][1] * mat_k[1][0];
 
-        Vector3::new(det_i, det_j, det_k)
-    }
-
-    pub fn dot(self, other: Vector3) -> f32 {
-        self.x * other.x + self.y * other.y + self.z * other.z
+        Vector3::new(det_i, -det_j, det_k)
     }
-}
 
-
-// ops
-impl ops::Add<Vector3> for Vector3 {
-    type Output = Vector3;
-
-    fn add(self, rhs: Vector3) -> Self::Output {
-        Vector3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    pub fn component_mul(self, other: Vector3) -> Vector3 {
+        Vector3::new(self.x * other.x, self.y * other.y, self.z * other.z)
     }
 }
 
-impl ops::Sub<Vector3> for Vector3 {
-    type Output = Vector3;
+impl_vector!(Vector3, f32, 3, x: 0, y: 1, z: 2);
+impl_float_vector!(Vector3, x, y, z);
+impl_swizzles!(Vector3 => Vector2 { xy: x y, xz: x z, yz: y z, yx: y x, zx: z x, zy: z y });
+impl_swizzles!(Vector3 => Vector3 { xzy: x z y, yxz: y x z, yzx: y z x, zxy: z x y, zyx: z y x });
 
-    fn sub(self, rhs: Vector3) -> Self::Output {
-        Vector3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+impl From<IVector3> for Vector3 {
+    fn from(vector: IVector3) -> Self {
+        Vector3::new(vector.x as f32, vector.y as f32, vector.z as f32)
     }
 }
-
-impl ops::Mul<Vector3> for Vector3 {
-    type Output = f32;
-
-    fn mul(self, rhs: Vector3) -> Self::Output {
-        self.dot(rhs)
-    }
-}
-
-impl ops::AddAssign<Vector3> for Vector3 {
-    fn add_assign(&mut self, rhs: Vector3) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
-    }
-}
-
-impl ops::SubAssign<Vector3> for Vector3 {
-    fn sub_assign(&mut self, rhs: Vector3) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-        self.z -= rhs.z;
-    }
-}
-
-impl ops::Add<f32> for Vector3 {
-    type Output = Vector3;
-
-    fn add(self, rhs: f32) -> Self::Output {
-        Vector3::new(self.x + rhs, self.y + rhs, self.z + rhs)
-    }
-}
-
-impl ops::Sub<f32> for Vector3 {
-    type Output = Vector3;
-
-    fn sub(self, rhs: f32) -> Self::Output {
-        Vector3::new(self.x - rhs, self.y - rhs, self.z - rhs)
-    }
-}
-
-impl ops::Mul<f32> for Vector3 {
-    type Output = Vector3;
-
-    fn mul(self, rhs: f32) -> Self::Output {
-        Vector3::new(self.x * rhs, self.y * rhs, self.z * rhs)
-    }
-}
-
-impl ops::Div<f32> for Vector3 {
-    type Output = Vector3;
-
-    fn div(self, rhs: f32) -> Self::Output {
-        Vector3::new(self.x * rhs, self.y * rhs, self.z * rhs)
-    }
-}
-
-impl ops::AddAssign<f32> for Vector3 {
-    fn add_assign(&mut self, rhs: f32) {
-        self.x += rhs;
-        self.y += rhs;
-        self.z += rhs;
-    }
-}
-
-impl ops::SubAssign<f32> for Vector3 {
-    fn sub_assign(&mut self, rhs: f32) {
-        self.x -= rhs;
-        self.y -= rhs;
-        self.z -= rhs;
-    }
-}
-
-impl ops::MulAssign<f32> for Vector3 {
-    fn mul_assign(&mut self, rhs: f32) {
-        self.x *= rhs;
-        self.y *= rhs;
-        self.z *= rhs;
-    }
-}
-
-impl ops::DivAssign<f32> for Vector3 {
-    fn div_assign(&mut self, rhs: f32) {
-        self.x /= rhs;
-        self.y /= rhs;
-        self.z /= rhs;
-    }
-}
-
-impl ops::Neg for Vector3 {
-    type Output = Vector3;
-
-    fn neg(self) -> Self::Output {
-        Vector3::new(-self.x, -self.y, -self.z)
-    }
-}
-
-impl ops::Index<usize> for Vector3 {
-    type Output = f32;
-
-    fn index(&self, index: usize) -> &Self::Output {
-        match index {
-            0 => &self.x,
-            1 => &self.y,
-            2 => &self.z,
-            _ => panic!("Index out of range!"),
-        }
-    }
-}
-
-//end of ops
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Vector2, Vector3};
+
+/// Four component vector, mostly used as homogeneous coordinates transformed by a `Matrix4x4`. Points have a `w` of
+/// `1.0` and directions a `w` of `0.0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "[f32; 4]", into = "[f32; 4]")]
+pub struct Vector4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Vector4 {
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Vector4 {
+        Vector4 { x, y, z, w }
+    }
+
+    /// creates vector from `vector` and `w`.
+    pub fn from_vector3(vector: Vector3, w: f32) -> Vector4 {
+        Vector4::new(vector.x, vector.y, vector.z, w)
+    }
+
+    /// The x, y and z components divided by `w`, the point the homogeneous coordinates describe. Returns the x, y
+    /// and z components as is if `w` is 0.
+    pub fn to_point(self) -> Vector3 {
+        if self.w == 0.0 {
+            return self.xyz();
+        }
+
+        self.xyz() / self.w
+    }
+}
+
+impl_vector!(Vector4, f32, 4, x: 0, y: 1, z: 2, w: 3);
+impl_float_vector!(Vector4, x, y, z, w);
+impl_swizzles!(Vector4 => Vector2 { xy: x y, zw: z w });
+impl_swizzles!(Vector4 => Vector3 { xyz: x y z, xyw: x y w });
@@ -0,0 +1,275 @@
+//! Macros implementing the operators, conversions and helpers every vector type shares, so `Vector2`, `Vector3`,
+//! `Vector4`, `IVector2`, `IVector3` and `UVector2` behave the same.
+
+/// Expands to `$scalar` once for every `$field`, used to build tuple types with a component per field.
+macro_rules! component_type {
+    ($field:ident, $scalar:ty) => {
+        $scalar
+    };
+}
+
+/// Implements the operators of `Vector3` for a vector type of `$scalar` components:
+/// - `+` and `-` with another vector, component-wise, and their assign variants.
+/// - `*` with another vector, the dot product.
+/// - `+`, `-`, `*` and `/` with a scalar, applied to every component, and their assign variants.
+/// - Indexing by component index, panics if the index is out of range.
+///
+/// Also implements `dot()`, `to_array()` and conversions from and to arrays and tuples of `$scalar`.
+macro_rules! impl_vector {
+    ($vector:ident, $scalar:ty, $count:literal, $($field:ident: $index:literal),+) => {
+        impl $vector {
+            pub fn to_array(self) -> [$scalar; $count] {
+                [$(self.$field),+]
+            }
+
+            pub fn dot(self, other: $vector) -> $scalar {
+                let mut sum = <$scalar>::default();
+                $(sum += self.$field * other.$field;)+
+                sum
+            }
+        }
+
+        impl std::ops::Add<$vector> for $vector {
+            type Output = $vector;
+
+            fn add(self, rhs: $vector) -> Self::Output {
+                $vector { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl std::ops::Sub<$vector> for $vector {
+            type Output = $vector;
+
+            fn sub(self, rhs: $vector) -> Self::Output {
+                $vector { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl std::ops::Mul<$vector> for $vector {
+            type Output = $scalar;
+
+            fn mul(self, rhs: $vector) -> Self::Output {
+                self.dot(rhs)
+            }
+        }
+
+        impl std::ops::AddAssign<$vector> for $vector {
+            fn add_assign(&mut self, rhs: $vector) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl std::ops::SubAssign<$vector> for $vector {
+            fn sub_assign(&mut self, rhs: $vector) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl std::ops::Add<$scalar> for $vector {
+            type Output = $vector;
+
+            fn add(self, rhs: $scalar) -> Self::Output {
+                $vector { $($field: self.$field + rhs),+ }
+            }
+        }
+
+        impl std::ops::Sub<$scalar> for $vector {
+            type Output = $vector;
+
+            fn sub(self, rhs: $scalar) -> Self::Output {
+                $vector { $($field: self.$field - rhs),+ }
+            }
+        }
+
+        impl std::ops::Mul<$scalar> for $vector {
+            type Output = $vector;
+
+            fn mul(self, rhs: $scalar) -> Self::Output {
+                $vector { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl std::ops::Div<$scalar> for $vector {
+            type Output = $vector;
+
+            fn div(self, rhs: $scalar) -> Self::Output {
+                $vector { $($field: self.$field / rhs),+ }
+            }
+        }
+
+        impl std::ops::AddAssign<$scalar> for $vector {
+            fn add_assign(&mut self, rhs: $scalar) {
+                $(self.$field += rhs;)+
+            }
+        }
+
+        impl std::ops::SubAssign<$scalar> for $vector {
+            fn sub_assign(&mut self, rhs: $scalar) {
+                $(self.$field -= rhs;)+
+            }
+        }
+
+        impl std::ops::MulAssign<$scalar> for $vector {
+            fn mul_assign(&mut self, rhs: $scalar) {
+                $(self.$field *= rhs;)+
+            }
+        }
+
+        impl std::ops::DivAssign<$scalar> for $vector {
+            fn div_assign(&mut self, rhs: $scalar) {
+                $(self.$field /= rhs;)+
+            }
+        }
+
+        impl std::ops::Index<usize> for $vector {
+            type Output = $scalar;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                match index {
+                    $($index => &self.$field,)+
+                    _ => panic!("Index out of range!"),
+                }
+            }
+        }
+
+        impl From<[$scalar; $count]> for $vector {
+            fn from(array: [$scalar; $count]) -> Self {
+                let [$($field),+] = array;
+                $vector { $($field),+ }
+            }
+        }
+
+        impl From<$vector> for [$scalar; $count] {
+            fn from(vector: $vector) -> Self {
+                vector.to_array()
+            }
+        }
+
+        impl From<($(component_type!($field, $scalar),)+)> for $vector {
+            fn from(tuple: ($(component_type!($field, $scalar),)+)) -> Self {
+                let ($($field,)+) = tuple;
+                $vector { $($field),+ }
+            }
+        }
+
+        impl From<$vector> for ($(component_type!($field, $scalar),)+) {
+            fn from(vector: $vector) -> Self {
+                ($(vector.$field,)+)
+            }
+        }
+    };
+}
+
+/// Implements negation and `abs()` for a vector type of signed components.
+macro_rules! impl_signed_vector {
+    ($vector:ident, $($field:ident),+) => {
+        impl $vector {
+            /// Absolute value of every component.
+            pub fn abs(self) -> $vector {
+                $vector { $($field: self.$field.abs()),+ }
+            }
+        }
+
+        impl std::ops::Neg for $vector {
+            type Output = $vector;
+
+            fn neg(self) -> Self::Output {
+                $vector { $($field: -self.$field),+ }
+            }
+        }
+    };
+}
+
+/// Implements component-wise `min()`, `max()` and `clamp()`.
+macro_rules! impl_component_bounds {
+    ($vector:ident, $($field:ident),+) => {
+        impl $vector {
+            /// Smaller of the components of the vectors, component by component.
+            pub fn min(self, other: $vector) -> $vector {
+                $vector { $($field: self.$field.min(other.$field)),+ }
+            }
+
+            /// Larger of the components of the vectors, component by component.
+            pub fn max(self, other: $vector) -> $vector {
+                $vector { $($field: self.$field.max(other.$field)),+ }
+            }
+
+            /// Every component restricted to the range of the same component of `min` and `max`. Unlike
+            /// `f32::clamp()` this does not panic if `min` is larger than `max`, `max` wins.
+            pub fn clamp(self, min: $vector, max: $vector) -> $vector {
+                self.max(min).min(max)
+            }
+        }
+    };
+}
+
+/// Implements the length and interpolation helpers of a vector type of `f32` components.
+macro_rules! impl_float_vector {
+    ($vector:ident, $($field:ident),+) => {
+        impl_signed_vector!($vector, $($field),+);
+        impl_component_bounds!($vector, $($field),+);
+
+        impl $vector {
+            pub fn get_length(self) -> f32 {
+                self.dot(self).sqrt()
+            }
+
+            /// Returns the vector as is if its length is 0.
+            pub fn normalize(self) -> $vector {
+                let length = self.get_length();
+
+                if length == 0.0 {
+                    return self;
+                }
+
+                self / length
+            }
+
+            /// Distance between the points the vectors describe.
+            pub fn distance(self, other: $vector) -> f32 {
+                (other - self).get_length()
+            }
+
+            /// Linear interpolation, returns `self` when `t` is 0 and `other` when `t` is 1. `t` is not clamped.
+            pub fn lerp(self, other: $vector, t: f32) -> $vector {
+                self + (other - self) * t
+            }
+
+            /// Direction bounced off a surface with `normal`. The normal has to be normalized.
+            pub fn reflect(self, normal: $vector) -> $vector {
+                self - normal * (2.0 * self.dot(normal))
+            }
+
+            /// Part of the vector pointing in the direction of `other`. Projecting on a zero vector returns zero.
+            pub fn project_on(self, other: $vector) -> $vector {
+                let length_squared = other.dot(other);
+
+                if length_squared == 0.0 {
+                    return other;
+                }
+
+                other * (self.dot(other) / length_squared)
+            }
+
+            /// Whether no component differs from the same component of `other` by more than `epsilon`.
+            pub fn approx_eq(self, other: $vector, epsilon: f32) -> bool {
+                true $(&& (self.$field - other.$field).abs() <= epsilon)+
+            }
+        }
+    };
+}
+
+/// Implements swizzle methods returning the listed components of a vector as another vector type, for example
+/// `impl_swizzles!(Vector3 => Vector2 { xz: x z })` adds `Vector3::xz()`.
+macro_rules! impl_swizzles {
+    ($vector:ident => $target:ident { $($name:ident: $($field:ident)+),+ $(,)? }) => {
+        impl $vector {
+            $(
+                #[doc = concat!("The ", stringify!($($field),+), " components.")]
+                pub fn $name(self) -> $target {
+                    $target::new($(self.$field),+)
+                }
+            )+
+        }
+    };
+}
@@ -0,0 +1,134 @@
+//! Behaviours are stateful scripts attached to game objects.
+
+use crate::structs::{EntityId, Scene};
+
+/// Timing of the frame a behaviour is called in.
+///
+/// # Fields
+/// - delta_time: Time between last frame release and this instant in seconds.
+/// - time_since_start: Time since the program was started in seconds.
+/// - frame: Number of the frame, starting from zero.
+#[derive(Clone, Copy, Debug)]
+pub struct FrameContext {
+    pub delta_time: f32,
+    pub time_since_start: f32,
+    pub frame: u64,
+}
+
+/// Script attached to a game object with `GameObject::add_behaviour()`. Every callback receives the scene, the id
+/// of the object the behaviour is attached to and the timing of the current frame. All callbacks do nothing by
+/// default.
+///
+/// While a callback runs the behaviour is taken out of its entity, so the components of the entity can be freely
+/// accessed with `scene.get_mut(id)`.
+///
+/// Closures `FnMut(&mut Scene, EntityId, &FrameContext)` are behaviours that only implement `on_update`.
+///
+/// # Examples
+/// ```
+/// use copper_engine::{
+///     math::{Quaternion, Transform, Vector3},
+///     object::behaviour::{Behaviour, FrameContext},
+///     structs::{EntityId, Scene},
+/// };
+///
+/// struct Spin {
+///     speed: f32,
+/// }
+///
+/// impl Behaviour for Spin {
+///     fn on_update(&mut self, scene: &mut Scene, id: EntityId, context: &FrameContext) {
+///         let spin = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), self.speed * context.delta_time);
+///
+///         if let Some(transform) = scene.get_mut::<Transform>(id) {
+///             transform.rotate(spin);
+///         }
+///     }
+/// }
+/// ```
+pub trait Behaviour {
+    /// Called once before the first update of the object, or before the first frame for objects spawned before the
+    /// engine starts.
+    fn on_awake(&mut self, _scene: &mut Scene, _id: EntityId, _context: &FrameContext) {}
+
+    /// Called every fixed step before the frame is updated, zero or more times per frame. `context.delta_time` is
+    /// `Scene::fixed_delta_time`.
+    fn on_fixed_update(&mut self, _scene: &mut Scene, _id: EntityId, _context: &FrameContext) {}
+
+    /// Called every frame.
+    fn on_update(&mut self, _scene: &mut Scene, _id: EntityId, _context: &FrameContext) {}
+
+    /// Called every frame after every object has been updated.
+    fn on_late_update(&mut self, _scene: &mut Scene, _id: EntityId, _context: &FrameContext) {}
+
+    /// Called at the end of the frame the object was despawned in. The object has already been removed from the
+    /// scene.
+    fn on_destroy(&mut self, _scene: &mut Scene, _id: EntityId, _context: &FrameContext) {}
+}
+
+/// Component holding the behaviours of an entity in the order they were added. Behaviours are added with
+/// `GameObject::add_behaviour()` or `Scene::add_behaviour()`.
+#[derive(Default)]
+pub struct Behaviours(pub(crate) Vec<Box<dyn Behaviour>>);
+
+impl Behaviours {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<F: FnMut(&mut Scene, EntityId, &FrameContext)> Behaviour for F {
+    fn on_update(&mut self, scene: &mut Scene, id: EntityId, context: &FrameContext) {
+        self(scene, id, context)
+    }
+}
+
+/// Calls `callback` for every behaviour of the entity. Stops if the entity is despawned by one of the calls.
+pub(crate) fn call_behaviours(
+    scene: &mut Scene,
+    id: EntityId,
+    context: &FrameContext,
+    callback: fn(&mut dyn Behaviour, &mut Scene, EntityId, &FrameContext),
+) {
+    let Some(Behaviours(behaviours)) = scene.get_mut::<Behaviours>(id) else {
+        return;
+    };
+    if behaviours.is_empty() {
+        return;
+    }
+
+    let mut behaviours = std::mem::take(behaviours);
+    for behaviour in &mut behaviours {
+        callback(behaviour.as_mut(), scene, id, context);
+
+        if !scene.contains(id) {
+            break;
+        }
+    }
+
+    if !scene.contains(id) {
+        scene.destroyed.push((id, behaviours));
+        return;
+    }
+
+    // Behaviours added during the calls are kept after the existing ones.
+    if let Some(Behaviours(added)) = scene.remove::<Behaviours>(id) {
+        behaviours.extend(added);
+    }
+    scene.insert(id, Behaviours(behaviours));
+}
+
+/// Calls `on_destroy` of the behaviours of every entity despawned since the last call.
+pub(crate) fn destroy_despawned(scene: &mut Scene, context: &FrameContext) {
+    while !scene.destroyed.is_empty() {
+        for (id, mut behaviours) in std::mem::take(&mut scene.destroyed) {
+            for behaviour in &mut behaviours {
+                behaviour.on_destroy(scene, id, context);
+            }
+        }
+    }
+}
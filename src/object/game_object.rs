@@ -1,79 +1,200 @@
 //! Object is 3d model with translation, scale, and rotation in scene space.  
 
 use super::{
-    vertex::{Normal, Vertex},
+    behaviour::{Behaviour, Behaviours},
+    MeshRenderer, Shader,
 };
+use crate::assets::Handle;
+use crate::blank_tick_update;
+use crate::ecs::{Bundle, Name};
+use crate::math::Transform;
 use crate::object::{model_loader::Model, Material};
-use crate::structs::Scene;
-use glium::{IndexBuffer, Program, VertexBuffer};
+use crate::structs::{EntityId, Scene};
+
+/// Component holding the functions of a game object, see `GameObject`.
+#[derive(Clone, Copy)]
+pub struct Hooks {
+    pub tick_update_func: fn(&mut Scene),
+    pub fixed_update_func: fn(&mut Scene),
+    pub on_awake: fn(&mut Scene),
+}
 
 /// # fields
 /// - name: Can be used to distinguish between objects and shows up in the debug menu. **Should be unique.**
-/// - model: Loaded `.obj` file should be loaded with `model_loading::model_loader::get_obj()`
-/// - position: Set of \[x, y, z] coordinates indicating the translation of the object form the world origin (0, 0, 0).
-/// - rotation: Set of \[x, y, z] f32. Indicates object's rotation in **radian**.
-/// - scale: Set of \[x, y, z] f32. Indicates the scale of the object on each axis.
+/// - model: Loaded `.obj` file should be loaded with `model_loading::model_loader::get_obj()`, or with
+///   `AssetServer::load_model()` to share it with other objects.
+/// - transform: Translation of the object from the origin of its parent, or the world origin (0, 0, 0) for objects without parent, its rotation and its scale on each axis.
 /// - tick_update_func: `tick_update_func` is called every drawn frame. For more info about function call order refer to github wiki pages.
+/// - fixed_update_func: `fixed_update_func` is called every fixed step, see `Scene::fixed_delta_time`. Does nothing unless set after `::new()`.
 /// - on_awake: `on_awake` is called once after model loading is completed.
+/// - material: Colors and texture of the object.
+/// - shader: Shader the object is drawn with. Defaults to the built-in shader, `Shader::standard()`.
+///
+/// Stateful scripts can be attached with `add_behaviour()`.
 ///
-/// Other fields should not be set by the user and should be left as `None`. Usage of `::new()` is strongly recommended.
+/// Game object is a `Bundle`. When spawned, it becomes an entity with the components `Name`, `Transform`,
+/// `MeshRenderer`, `Material`, `Hooks` and, if it has any, `Behaviours`.
+///
+/// Usage of `::new()` is strongly recommended.
 pub struct GameObject {
     pub name: String,
-    pub model: Model,
-    pub translation: [f32; 3],
-    pub rotation: [f32; 3],
-    pub scale: [f32; 3],
+    pub model: Handle<Model>,
+    pub transform: Transform,
     pub tick_update_func: fn(&mut Scene),
+    pub fixed_update_func: fn(&mut Scene),
     pub on_awake: fn(&mut Scene),
 
     pub material: Material,
+    pub shader: Handle<Shader>,
 
-    pub(crate) program: Option<Program>,
-    pub(crate) vertices: Option<VertexBuffer<Vertex>>,
-    pub(crate) normals: Option<VertexBuffer<Normal>>,
-    pub(crate) indices: Option<IndexBuffer<u16>>,
+    pub(crate) behaviours: Vec<Box<dyn Behaviour>>,
+    pub(crate) spawn_parent: Option<usize>,
 }
 
 impl GameObject {
-    /// creates new 3d model object
+    /// creates new 3d model object. Takes a `Model` or a `Handle<Model>`.
     ///
     /// # Examples
     /// ```
-    /// let game_object = Object::new(
+    /// use copper_engine::{
+    ///     blank_on_awake, blank_tick_update,
+    ///     math::{Transform, Vector3},
+    ///     object::{model_loader::get_obj, GameObject, Material},
+    /// };
+    ///
+    /// let game_object = GameObject::new(
     ///     "Unique name",
-    ///     get_obj("path/to/the/model.obj"),
-    ///     [0.0, 0.0, 0.0],
-    ///     [0.0, 0.0, 0.0],
-    ///     [1.0, 1.0, 1.0],
+    ///     get_obj("models/ico.obj"),
+    ///     Transform::from_translation(Vector3::new(0.0, 0.0, 10.0)),
+    ///     Material::new([1.0, 0.2, 0.3]),
     ///     blank_tick_update,
     ///     blank_on_awake,
     /// );
     /// ```
-
     pub fn new(
         name: &str,
-        model: Model,
-        position: [f32; 3],
-        rotation: [f32; 3],
-        scale: [f32; 3],
+        model: impl Into<Handle<Model>>,
+        transform: Transform,
         material: Material,
         tick_update_func: fn(&mut Scene),
         on_awake: fn(&mut Scene),
     ) -> Self {
         Self {
             name: String::from(name),
-            model: model,
-            translation: position,
-            rotation: rotation,
-            scale: scale,
+            model: model.into(),
+            transform,
             material,
+            shader: Shader::standard(),
             tick_update_func: tick_update_func,
+            fixed_update_func: blank_tick_update,
             on_awake: on_awake,
 
-            program: None,
-            vertices: None,
-            normals: None,
-            indices: None,
+            behaviours: vec![],
+            spawn_parent: None,
+        }
+    }
+
+    /// attaches behaviour to the object. Behaviours are called in the order they were added, after
+    /// `tick_update_func` and `on_awake`. Behaviours added to an object that is already awake do not receive
+    /// `on_awake`.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::{
+    ///     blank_on_awake, blank_tick_update,
+    ///     math::Transform,
+    ///     object::{model_loader::Model, GameObject, Material},
+    /// };
+    ///
+    /// let mut bounces = 0;
+    /// let mut game_object = GameObject::new(
+    ///     "Ball",
+    ///     Model::uv_sphere(1.0, 16, 32),
+    ///     Transform::identity(),
+    ///     Material::new([1.0, 0.2, 0.3]),
+    ///     blank_tick_update,
+    ///     blank_on_awake,
+    /// );
+    /// game_object.add_behaviour(move |scene: &mut _, id, _context: &_| {
+    ///     bounces += 1;
+    /// });
+    /// ```
+    pub fn add_behaviour(&mut self, behaviour: impl Behaviour + 'static) -> &mut Self {
+        self.behaviours.push(Box::new(behaviour));
+        self
+    }
+
+    /// attaches behaviour to the object, like `add_behaviour()` but by value for chaining after `::new()`.
+    pub fn with_behaviour(mut self, behaviour: impl Behaviour + 'static) -> Self {
+        self.add_behaviour(behaviour);
+        self
+    }
+
+    /// creates one game object for each model, for example for each mesh returned by
+    /// `model_loader::load_obj_meshes()`. Objects are named after their models and use the material of their model.
+    /// `material` is used for models without one. Materials can be overridden afterwards through
+    /// `GameObject::material`.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::{blank_on_awake, blank_tick_update, math::Transform, object::{model_loader::load_obj_meshes, GameObject, Material}};
+    ///
+    /// let game_objects = GameObject::from_models(
+    ///     load_obj_meshes("models/ico.obj").unwrap(),
+    ///     Transform::identity(),
+    ///     Material::new([1.0, 0.2, 0.3]),
+    ///     blank_tick_update,
+    ///     blank_on_awake,
+    /// );
+    /// ```
+    pub fn from_models(
+        models: Vec<Model>,
+        transform: Transform,
+        material: Material,
+        tick_update_func: fn(&mut Scene),
+        on_awake: fn(&mut Scene),
+    ) -> Vec<Self> {
+        models
+            .into_iter()
+            .map(|model| {
+                let name = model.name.clone();
+                let material = model.material.clone().unwrap_or_else(|| material.clone());
+                Self::new(
+                    &name,
+                    model,
+                    transform,
+                    material,
+                    tick_update_func,
+                    on_awake,
+                )
+            })
+            .collect()
+    }
+}
+
+impl Bundle for GameObject {
+    fn insert_into(self, scene: &mut Scene, id: EntityId) {
+        scene.insert(id, Name(self.name));
+        scene.insert(id, self.transform);
+        scene.insert(
+            id,
+            MeshRenderer {
+                model: self.model,
+                shader: self.shader,
+            },
+        );
+        scene.insert(id, self.material);
+        scene.insert(
+            id,
+            Hooks {
+                tick_update_func: self.tick_update_func,
+                fixed_update_func: self.fixed_update_func,
+                on_awake: self.on_awake,
+            },
+        );
+
+        if !self.behaviours.is_empty() {
+            scene.insert(id, Behaviours(self.behaviours));
         }
     }
 }
@@ -0,0 +1,432 @@
+//! Loader for glTF 2.0 models. Supports both `.gltf` files with embedded or external buffers and textures and
+//! binary `.glb` containers.
+
+use gltf::{image::Format, mesh::Mode};
+use image::RgbaImage;
+use std::path::Path;
+
+use super::{
+    model_loader::{
+        load_texture_unshared, Indices, Model, ModelLoadError, NormalMode, TextureLoader,
+    },
+    vertex::{Normal, TexCoord, Vertex},
+    GameObject, Material, Texture,
+};
+use crate::{
+    assets::{resolve, Handle},
+    math::{Matrix4x4, Quaternion, Transform, Vector3},
+    structs::Scene,
+};
+
+/// Converts a decoded glTF image into an RGBA texture.
+fn convert_image(data: &gltf::image::Data) -> Result<Texture, ModelLoadError> {
+    let pixels: Vec<u8> = match data.format {
+        Format::R8G8B8A8 => data.pixels.clone(),
+        Format::R8G8B8 => data
+            .pixels
+            .chunks(3)
+            .flat_map(|pixel| [pixel[0], pixel[1], pixel[2], 255])
+            .collect(),
+        Format::R8G8 => data
+            .pixels
+            .chunks(2)
+            .flat_map(|pixel| [pixel[0], pixel[1], 0, 255])
+            .collect(),
+        Format::R8 => data
+            .pixels
+            .iter()
+            .flat_map(|pixel| [*pixel, *pixel, *pixel, 255])
+            .collect(),
+        _ => return Err(ModelLoadError::UnsupportedTextureFormat),
+    };
+
+    let image = RgbaImage::from_raw(data.width, data.height, pixels)
+        .ok_or(ModelLoadError::UnsupportedTextureFormat)?;
+
+    Ok(Texture { image })
+}
+
+/// Converts a PBR metallic-roughness material into a `Material`.
+///
+/// The base color becomes the diffuse color and half of it the ambient color. Metallic surfaces tint their
+/// highlights with the base color and rough surfaces get a lower shininess. `textures` holds the texture of every
+/// image of the file, so materials using the same image share its texture.
+fn convert_material(material: &gltf::Material, textures: &[Handle<Texture>]) -> Material {
+    let pbr = material.pbr_metallic_roughness();
+    let [r, g, b, a] = pbr.base_color_factor();
+    let metallic = pbr.metallic_factor();
+    let roughness = pbr.roughness_factor().max(0.01);
+
+    let specular = |channel: f32| 0.04 + (channel - 0.04) * metallic;
+
+    let albedo_texture = pbr
+        .base_color_texture()
+        .map(|info| textures[info.texture().source().index()].clone());
+
+    Material {
+        ambient_color: Vector3::new(r * 0.5, g * 0.5, b * 0.5),
+        diffuse_color: Vector3::new(r, g, b),
+        specular_color: Vector3::new(specular(r), specular(g), specular(b)),
+        shininess: (2.0 / roughness.powi(4) - 2.0).clamp(1.0, 1024.0),
+        dissolve: if material.alpha_mode() == gltf::material::AlphaMode::Blend {
+            a
+        } else {
+            1.0
+        },
+        albedo_texture,
+    }
+}
+
+/// Converts a single triangle primitive into a `Model`.
+fn convert_primitive(
+    name: String,
+    primitive: &gltf::Primitive,
+    buffers: &[gltf::buffer::Data],
+    materials: &[Material],
+) -> Result<Model, ModelLoadError> {
+    if primitive.mode() != Mode::Triangles {
+        return Err(ModelLoadError::UnsupportedPrimitive);
+    }
+
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+
+    let positions: Vec<Vertex> = reader
+        .read_positions()
+        .ok_or(ModelLoadError::MissingPositions)?
+        .map(|position| Vertex { position })
+        .collect();
+
+    let normals: Vec<Normal> = reader
+        .read_normals()
+        .map(|normals| normals.map(|normal| Normal { normal }).collect())
+        .unwrap_or_default();
+
+    // glTF places the texture origin to the top left corner, the engine to the bottom left.
+    let tex_coords: Vec<TexCoord> = reader
+        .read_tex_coords(0)
+        .map(|tex_coords| {
+            tex_coords
+                .into_f32()
+                .map(|[u, v]| TexCoord {
+                    tex_coords: [u, 1.0 - v],
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let indices: Vec<u32> = match reader.read_indices() {
+        Some(indices) => indices.into_u32().collect(),
+        None => (0..positions.len() as u32).collect(),
+    };
+
+    let material = match primitive.material().index() {
+        Some(index) => materials.get(index).cloned(),
+        None => None,
+    };
+
+    let mut model = Model {
+        name,
+        positions,
+        normals,
+        tex_coords,
+        indices: Indices::from(indices),
+        material,
+    };
+
+    model.validate()?;
+
+    if model.normals.is_empty() {
+        model.generate_normals(NormalMode::Smooth);
+    }
+
+    Ok(model)
+}
+
+/// Converts every primitive of a mesh into a model. Models are named after `name`, and numbered if the mesh has
+/// several primitives.
+fn convert_mesh(
+    name: &str,
+    mesh: &gltf::Mesh,
+    buffers: &[gltf::buffer::Data],
+    materials: &[Material],
+) -> Result<Vec<Model>, ModelLoadError> {
+    let primitive_count = mesh.primitives().len();
+
+    mesh.primitives()
+        .enumerate()
+        .map(|(i, primitive)| {
+            let name = if primitive_count > 1 {
+                format!("{} {}", name, i)
+            } else {
+                String::from(name)
+            };
+
+            convert_primitive(name, &primitive, buffers, materials)
+        })
+        .collect()
+}
+
+/// Data shared by every node of an imported scene.
+struct NodeContext<'a> {
+    buffers: &'a [gltf::buffer::Data],
+    materials: &'a [Material],
+    tick_update_func: fn(&mut Scene),
+    on_awake: fn(&mut Scene),
+}
+
+/// Creates game objects for the meshes of `node` and its children. `parent_matrix` is the transform of the parent
+/// node relative to `parent`, the game object of the closest ancestor with a mesh.
+fn convert_node(
+    node: &gltf::Node,
+    parent_matrix: Matrix4x4,
+    parent: Option<usize>,
+    context: &NodeContext,
+    game_objects: &mut Vec<GameObject>,
+) -> Result<(), ModelLoadError> {
+    // The column-major matrices of glTF have the same layout as `Matrix4x4`, the node transform is applied first.
+    let local_matrix = Matrix4x4::from_array(node.transform().matrix()) * parent_matrix;
+
+    let mut children_parent = parent;
+    let mut children_matrix = local_matrix;
+
+    if let Some(mesh) = node.mesh() {
+        let name = node
+            .name()
+            .or_else(|| mesh.name())
+            .map(String::from)
+            .unwrap_or_else(|| format!("Node {}", node.index()));
+
+        let ([tx, ty, tz], [x, y, z, w], [sx, sy, sz]) = gltf::scene::Transform::Matrix {
+            matrix: local_matrix.matrix,
+        }
+        .decomposed();
+        let transform = Transform::new(
+            Vector3::new(tx, ty, tz),
+            Quaternion::new(x, y, z, w),
+            Vector3::new(sx, sy, sz),
+        );
+
+        // Children of the node are attached to the game object of its first primitive.
+        children_parent = Some(game_objects.len());
+        children_matrix = Matrix4x4::identity();
+
+        for model in convert_mesh(&name, &mesh, context.buffers, context.materials)? {
+            let name = model.name.clone();
+            let material = model.material.clone().unwrap_or_else(default_material);
+
+            let mut game_object = GameObject::new(
+                &name,
+                model,
+                transform,
+                material,
+                context.tick_update_func,
+                context.on_awake,
+            );
+            game_object.spawn_parent = parent;
+            game_objects.push(game_object);
+        }
+    }
+
+    for child in node.children() {
+        convert_node(
+            &child,
+            children_matrix,
+            children_parent,
+            context,
+            game_objects,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Material of primitives without one, the default material of the glTF specification.
+fn default_material() -> Material {
+    Material {
+        ambient_color: Vector3::new(0.5, 0.5, 0.5),
+        diffuse_color: Vector3::new(1.0, 1.0, 1.0),
+        specular_color: Vector3::new(1.0, 1.0, 1.0),
+        shininess: 1.0,
+        dissolve: 1.0,
+        albedo_texture: None,
+    }
+}
+
+/// Imports glTF file and converts its materials.
+fn import(
+    path: &str,
+    load_texture: TextureLoader,
+) -> Result<(gltf::Document, Vec<gltf::buffer::Data>, Vec<Material>), ModelLoadError> {
+    let path = resolve(path);
+    let directory = path.parent().unwrap_or_else(|| Path::new("./"));
+    let gltf::Gltf { document, blob } = gltf::Gltf::open(&path)?;
+    let buffers = gltf::import_buffers(&document, Some(directory), blob)?;
+
+    // Image files are loaded with `load_texture`, so the asset server can share them. Embedded images and URIs that
+    // need decoding, like percent-encoded names, are decoded by `gltf`.
+    let mut textures = vec![];
+    for image in document.images() {
+        textures.push(match image.source() {
+            gltf::image::Source::Uri { uri, .. } if !uri.contains([':', '%']) => {
+                load_texture(&directory.join(uri))?
+            }
+            source => {
+                let data = gltf::image::Data::from_source(source, Some(directory), &buffers)?;
+                convert_image(&data)?.into()
+            }
+        });
+    }
+
+    let materials = document
+        .materials()
+        .map(|material| convert_material(&material, &textures))
+        .collect();
+
+    Ok((document, buffers, materials))
+}
+
+/// loads every mesh primitive of glTF or GLB file into copper engine compatible format. Node transforms are
+/// ignored, use `load_gltf_objects()` to place the meshes like in the file.
+///
+/// # Errors
+/// - `ModelLoadError::Gltf`: File could not be read or parsed
+/// - `ModelLoadError::NoMeshes`: File does not contain any meshes
+/// - `ModelLoadError::MissingPositions`: A primitive has no vertex positions
+/// - `ModelLoadError::UnsupportedPrimitive`: A primitive is not made of triangles
+/// - `ModelLoadError::IndexOutOfRange`: An index of a primitive points past its last vertex
+/// - `ModelLoadError::MalformedNormals`: A primitive has fewer or more normals than vertices
+/// - `ModelLoadError::MalformedTexCoords`: A primitive has fewer or more texture coordinates than vertices
+/// - `ModelLoadError::UnsupportedTextureFormat`: A texture uses 16 bit or floating point pixels
+///
+/// # Examples
+/// ```
+/// use copper_engine::object::{gltf_loader::load_gltf_meshes, model_loader::ModelLoadError};
+///
+/// // GLB container with the texture embedded in its binary chunk.
+/// let meshes = load_gltf_meshes("models/robot.glb").unwrap();
+/// assert_eq!(meshes.len(), 2);
+/// assert_eq!(meshes[0].name, "Body");
+/// assert_eq!(meshes[1].name, "Arm");
+/// assert_eq!(meshes[0].indices.len(), 6);
+///
+/// // Base color factor and texture of the unmetallic, fully rough material.
+/// let painted = meshes[0].material.as_ref().unwrap();
+/// assert_eq!(painted.diffuse_color.to_array(), [1.0, 0.5, 0.25]);
+/// assert_eq!(painted.ambient_color.to_array(), [0.5, 0.25, 0.125]);
+/// assert!((painted.specular_color.x - 0.04).abs() < 1e-6);
+/// assert_eq!(painted.shininess, 1.0);
+/// assert_eq!(painted.albedo_texture.as_ref().unwrap().image.dimensions(), (2, 2));
+///
+/// // The arm has no normals in the file, they are generated.
+/// let metal = meshes[1].material.as_ref().unwrap();
+/// assert!((metal.specular_color.x - 0.8).abs() < 1e-6);
+/// assert!((metal.shininess - 30.0).abs() < 1e-4);
+/// assert!(metal.albedo_texture.is_none());
+/// assert_eq!(meshes[1].normals.len(), 3);
+///
+/// // The indices of the triangle point to a sixth vertex.
+/// assert!(matches!(
+///     load_gltf_meshes("models/broken_indices.gltf"),
+///     Err(ModelLoadError::IndexOutOfRange { index: 5, vertex_count: 3 })
+/// ));
+/// ```
+pub fn load_gltf_meshes(path: &str) -> Result<Vec<Model>, ModelLoadError> {
+    load_gltf_meshes_with(path, &mut load_texture_unshared)
+}
+
+/// Same as `load_gltf_meshes()`, loading the image files of the materials with `load_texture`.
+pub(crate) fn load_gltf_meshes_with(
+    path: &str,
+    load_texture: TextureLoader,
+) -> Result<Vec<Model>, ModelLoadError> {
+    let (document, buffers, materials) = import(path, load_texture)?;
+
+    let mut models = vec![];
+    for mesh in document.meshes() {
+        let name = mesh
+            .name()
+            .map(String::from)
+            .unwrap_or_else(|| format!("Mesh {}", mesh.index()));
+
+        models.append(&mut convert_mesh(&name, &mesh, &buffers, &materials)?);
+    }
+
+    if models.is_empty() {
+        return Err(ModelLoadError::NoMeshes);
+    }
+
+    Ok(models)
+}
+
+/// loads glTF or GLB file into game objects. Every mesh primitive of the default scene becomes one game object,
+/// named after its node. Game objects are parented like their nodes once spawned with `Scene::spawn_batch()` or
+/// `Scene::new()`. Nodes without meshes do not become game objects, their transforms are combined into their
+/// children instead.
+///
+/// # Errors
+/// Same as `load_gltf_meshes()`.
+///
+/// # Examples
+/// ```
+/// # use copper_engine::{input::InputManager, math::Vector3, Camera};
+/// use copper_engine::{blank_on_awake, blank_tick_update, object::gltf_loader::load_gltf_objects, structs::Scene};
+///
+/// // glTF file with external buffer and texture. The arm is a child of the shoulder node without mesh, which is a
+/// // child of the robot.
+/// let game_objects = load_gltf_objects("models/robot.gltf", blank_tick_update, blank_on_awake).unwrap();
+/// assert_eq!(game_objects.len(), 2);
+/// assert!(game_objects[0].material.albedo_texture.is_some());
+///
+/// // The shoulder translation is combined into the arm, applied after the scale of the arm.
+/// let arm = &game_objects[1].transform;
+/// assert!(arm.translation.approx_eq(Vector3::new(2.0, 0.0, 1.0), 1e-6));
+/// assert!(arm.scale.approx_eq(Vector3::new(2.0, 2.0, 2.0), 1e-6));
+///
+/// # let camera = Camera::new(0.1, 100.0, 1.0, Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), blank_tick_update, blank_on_awake);
+/// let mut scene = Scene::new(vec![], vec![], InputManager::new(), camera);
+/// scene.spawn_batch(game_objects);
+///
+/// let robot = scene.find("Robot").unwrap();
+/// let arm = scene.find("Arm").unwrap();
+/// assert_eq!(scene.parent(arm), Some(robot));
+///
+/// // The robot is turned by 90 degrees to the left and lifted by 1.
+/// let position = scene.world_position(arm).unwrap();
+/// assert!(position.approx_eq(Vector3::new(1.0, 1.0, -2.0), 1e-5));
+/// ```
+pub fn load_gltf_objects(
+    path: &str,
+    tick_update_func: fn(&mut Scene),
+    on_awake: fn(&mut Scene),
+) -> Result<Vec<GameObject>, ModelLoadError> {
+    let (document, buffers, materials) = import(path, &mut load_texture_unshared)?;
+
+    let scene = document
+        .default_scene()
+        .or_else(|| document.scenes().next())
+        .ok_or(ModelLoadError::NoMeshes)?;
+
+    let context = NodeContext {
+        buffers: &buffers,
+        materials: &materials,
+        tick_update_func,
+        on_awake,
+    };
+
+    let mut game_objects = vec![];
+    for node in scene.nodes() {
+        convert_node(
+            &node,
+            Matrix4x4::identity(),
+            None,
+            &context,
+            &mut game_objects,
+        )?;
+    }
+
+    if game_objects.is_empty() {
+        return Err(ModelLoadError::NoMeshes);
+    }
+
+    Ok(game_objects)
+}
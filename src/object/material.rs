@@ -1,3 +1,55 @@
+//! Material describes how the surface of a game object is shaded.
+
+use super::Texture;
+use crate::assets::Handle;
+use crate::math::Vector3;
+
+/// Specular exponent of `Material::new()`, also used for MTL materials without `Ns`.
+pub(crate) const DEFAULT_SHININESS: f32 = 16.0;
+
+/// # Fields
+/// - ambient_color: r, g and b as x, y and z in range 0.0-1.0. Color of the object without direct light.
+/// - diffuse_color: r, g and b as x, y and z in range 0.0-1.0. Color of the object in direct light.
+/// - specular_color: r, g and b as x, y and z in range 0.0-1.0. Color of the highlights.
+/// - shininess: Specular exponent. Higher values give smaller and sharper highlights.
+/// - dissolve: Opacity of the object where `1.0` is fully opaque.
+/// - albedo_texture: Optional texture multiplied with the color of the object. Uses texture coordinates of the model.
+///   Materials with the same texture handle share the uploaded texture.
+///
+/// Models loaded from OBJ files get their materials from the MTL file, see `Model::material`. The albedo texture is
+/// the only texture of a material, other texture maps of the MTL file are ignored.
+/// Usage of `::new()` is strongly recommended!
+#[derive(Clone, Debug)]
 pub struct Material {
-    pub ambient_color: [f32; 3],
-}
\ No newline at end of file
+    pub ambient_color: Vector3,
+    pub diffuse_color: Vector3,
+    pub specular_color: Vector3,
+    pub shininess: f32,
+    pub dissolve: f32,
+    pub albedo_texture: Option<Handle<Texture>>,
+}
+
+impl Material {
+    /// creates opaque untextured material. Diffuse color is twice the ambient color and highlights are white. The
+    /// color can also be given as \[r, g, b] array.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::object::{Material, Texture};
+    ///
+    /// let mut material = Material::new([1.0, 1.0, 1.0]);
+    /// material.albedo_texture = Some(Texture::load("textures/debug_map.png").unwrap().into());
+    /// ```
+    pub fn new(ambient_color: impl Into<Vector3>) -> Self {
+        let ambient_color = ambient_color.into();
+
+        Self {
+            ambient_color,
+            diffuse_color: ambient_color * 2.0,
+            specular_color: Vector3::new(1.0, 1.0, 1.0),
+            shininess: DEFAULT_SHININESS,
+            dissolve: 1.0,
+            albedo_texture: None,
+        }
+    }
+}
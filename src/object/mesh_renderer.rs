@@ -0,0 +1,186 @@
+//! Mesh renderer draws a model at the world matrix of its entity.
+
+use super::{
+    model_loader::{Indices, Model},
+    texture::upload_texture,
+    vertex::{Normal, TexCoord, Vertex},
+    Material, Shader,
+};
+use crate::assets::{Asset, Handle};
+use crate::ecs::{RenderContext, RenderSystem, WorldMatrix};
+use crate::error::EngineError;
+use crate::math::Transform;
+use crate::structs::Scene;
+use glium::{index::IndexBufferAny, index::PrimitiveType, Display, Surface, VertexBuffer};
+
+/// Component of entities drawn by the engine. Entities are drawn by the `MeshRendererSystem` if they also have a
+/// `Transform` and a `Material`.
+///
+/// # Fields
+/// - model: Model drawn by the renderer. Renderers with the same model handle share its GPU buffers.
+/// - shader: Shader the model is drawn with. Renderers with the same shader handle share its compiled program.
+///
+/// Models and shaders are uploaded to the GPU by the engine before the first frame they are drawn in. Usage of
+/// `::new()` is strongly recommended!
+pub struct MeshRenderer {
+    pub model: Handle<Model>,
+    pub shader: Handle<Shader>,
+}
+
+impl MeshRenderer {
+    /// creates renderer of the model drawn with the built-in shader. Takes a `Model` or a `Handle<Model>`.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::object::{model_loader::Model, MeshRenderer};
+    ///
+    /// let renderer = MeshRenderer::new(Model::cube(1.0));
+    /// assert!(!renderer.is_uploaded());
+    /// ```
+    pub fn new(model: impl Into<Handle<Model>>) -> Self {
+        Self {
+            model: model.into(),
+            shader: Shader::standard(),
+        }
+    }
+
+    /// Whether the model and shader have been uploaded to the GPU.
+    pub fn is_uploaded(&self) -> bool {
+        Handle::is_uploaded(&self.model) && Handle::is_uploaded(&self.shader)
+    }
+}
+
+/// GPU buffers of a model, uploaded once for all renderers sharing the model.
+pub struct MeshBuffers {
+    pub(crate) vertices: VertexBuffer<Vertex>,
+    pub(crate) normals: VertexBuffer<Normal>,
+    pub(crate) tex_coords: VertexBuffer<TexCoord>,
+    pub(crate) indices: IndexBufferAny,
+}
+
+impl Asset for Model {
+    type Gpu = MeshBuffers;
+}
+
+/// Render system drawing every entity with `Transform`, `MeshRenderer` and `Material` at its `WorldMatrix`, which
+/// is computed from the `Transform`. Models, shaders and textures that are not on the GPU yet are uploaded first,
+/// assets shared by several renderers only once. Entities without albedo texture are drawn with the white texture of
+/// the `RenderContext`.
+///
+/// Every scene starts with one, as its first render system.
+pub struct MeshRendererSystem;
+
+impl RenderSystem for MeshRendererSystem {
+    fn render(
+        &mut self,
+        scene: &mut Scene,
+        context: &mut RenderContext,
+    ) -> Result<(), EngineError> {
+        upload_mesh_renderers(context.display, scene)?;
+
+        let light = [0.0, 10.0, -5.0f32];
+
+        for (_, (_, world_matrix, object, material)) in
+            scene.query::<(&Transform, &WorldMatrix, &MeshRenderer, &Material)>()
+        {
+            let (Some(mesh), Some(program)) = (object.model.gpu(), object.shader.gpu()) else {
+                continue;
+            };
+
+            let position_matrix = world_matrix.0.matrix;
+
+            let params = glium::DrawParameters {
+                depth: glium::Depth {
+                    test: glium::draw_parameters::DepthTest::IfLess,
+                    write: true,
+                    ..Default::default()
+                },
+                blend: if material.dissolve < 1.0 {
+                    glium::Blend::alpha_blending()
+                } else {
+                    glium::Blend::default()
+                },
+                backface_culling: glium::draw_parameters::BackfaceCullingMode::CullClockwise,
+                ..Default::default()
+            };
+
+            let albedo_texture = material
+                .albedo_texture
+                .as_ref()
+                .and_then(|texture| texture.gpu())
+                .unwrap_or(context.white_texture);
+
+            context.target.draw(
+                (&mesh.vertices, &mesh.normals, &mesh.tex_coords),
+                &mesh.indices,
+                program,
+                &uniform! {
+                    model_pos_mat: position_matrix,
+                    u_light: light,
+                    u_ambient_color: material.ambient_color.to_array(),
+                    u_diffuse_color: material.diffuse_color.to_array(),
+                    u_specular_color: material.specular_color.to_array(),
+                    u_shininess: material.shininess,
+                    u_dissolve: material.dissolve,
+                    u_albedo: albedo_texture,
+                    projection: context.projection.matrix,
+                    view: context.view.matrix,
+                },
+                &params,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Uploads models, shaders and textures of mesh renderers that are not on the GPU yet. Assets shared by several
+/// renderers are uploaded only once.
+pub(crate) fn upload_mesh_renderers(
+    display: &Display,
+    scene: &mut Scene,
+) -> Result<(), EngineError> {
+    for (_, (object, material)) in scene.query::<(&MeshRenderer, &Material)>() {
+        object.model.upload(|model| upload_model(display, model))?;
+
+        object.shader.upload(|shader| {
+            glium::Program::from_source(
+                display,
+                &shader.vertex_source,
+                &shader.fragment_source,
+                None,
+            )
+            .map_err(EngineError::from)
+        })?;
+
+        if let Some(texture) = &material.albedo_texture {
+            texture.upload(|texture| upload_texture(display, texture))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Uploads vertex, normal, texture coordinate and index buffers of the model to the GPU.
+fn upload_model(display: &Display, model: &Model) -> Result<MeshBuffers, EngineError> {
+    Ok(MeshBuffers {
+        vertices: glium::VertexBuffer::new(display, &model.positions)?,
+        normals: glium::VertexBuffer::new(display, &model.normals)?,
+        tex_coords: if model.tex_coords.is_empty() {
+            let empty = TexCoord {
+                tex_coords: [0.0, 0.0],
+            };
+            glium::VertexBuffer::new(display, &vec![empty; model.positions.len()])?
+        } else {
+            glium::VertexBuffer::new(display, &model.tex_coords)?
+        },
+        indices: match &model.indices {
+            Indices::U16(indices) => {
+                glium::IndexBuffer::new(display, PrimitiveType::TrianglesList, indices)?.into()
+            }
+            Indices::U32(indices) => {
+                glium::IndexBuffer::new(display, PrimitiveType::TrianglesList, indices)?.into()
+            }
+        },
+    })
+}
@@ -1,7 +1,17 @@
+pub mod behaviour;
 pub mod model_loader;
+pub mod gltf_loader;
 pub mod vertex;
 pub mod game_object;
+pub mod mesh_renderer;
 pub mod material;
+pub mod texture;
+pub mod primitives;
+pub mod shader;
 
-pub use game_object::GameObject;
-pub use material::Material;
\ No newline at end of file
+pub use behaviour::{Behaviour, Behaviours, FrameContext};
+pub use game_object::{GameObject, Hooks};
+pub use material::Material;
+pub use mesh_renderer::MeshRenderer;
+pub use shader::Shader;
+pub use texture::Texture;
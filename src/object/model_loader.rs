@@ -1,72 +1,353 @@
+use std::{error::Error, fmt, path::Path};
 
-use super::vertex::{Vertex, Normal};
+use super::vertex::{Vertex, Normal, TexCoord};
+use super::material::DEFAULT_SHININESS;
+use super::{Material, Texture};
+use crate::assets::{resolve, Handle};
+use crate::math::Vector3;
 
 // **Should not be created by user: use `::get_obj()`**
 #[derive(Debug)]
 pub struct Model {
+    pub name: String,
     pub positions: Vec<Vertex>,
     pub normals: Vec<Normal>,
-    pub indices: Vec<u16>,
+    pub tex_coords: Vec<TexCoord>,
+    pub indices: Indices,
+    /// Material assigned to the mesh in the MTL file of the model, if any.
+    pub material: Option<Material>,
 }
 
-/// loads and parses wavefront OBJ file into copper engine compatible format.
+/// Method used to generate normals with `Model::generate_normals()`.
 ///
-/// # Errors
-/// - Object parsing is failed:
-///     - OpenFileFailed
-///     - ReadError
-///     - UnrecognizedCharacter
-///     - PositionParseError
-///     - NormalParseError
-///     - TexcoordParseError
-///     - FaceParseError
-///     - MaterialParseError
-///     - InvalidObjectName
-///     - InvalidPolygon
-///     - FaceVertexOutOfBounds
-///     - FaceTexCoordOutOfBounds
-///     - FaceNormalOutOfBounds
-///     - FaceColorOutOfBounds
-///     - InvalidLoadOptionConfig
-///     - GenericFailure
-/// - Vertexes have been parsed invalidly
-/// - Normals have been parsed invalidly
-/// - Models indices exceed u16 max limit *65 535* or are smaller than *0*
+/// # Variants
+/// - Smooth: Shared vertices get the average of the triangle normals, weighted by the angles at the vertex.
+/// - Flat: Every triangle gets its own vertices with the normal of the triangle. Gives a faceted look.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NormalMode {
+    Smooth,
+    Flat,
+}
+
+impl Model {
+    /// replaces normals of the model with generated ones. Models loaded without normals get smooth normals
+    /// automatically.
+    ///
+    /// # Panics
+    /// Panics if an index is out of range of the vertices, or the model has texture coordinates but not one for
+    /// every vertex. Loaded models are checked for both.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::object::model_loader::{get_obj, NormalMode};
+    ///
+    /// let mut model = get_obj("models/ico.obj");
+    /// model.generate_normals(NormalMode::Flat);
+    ///
+    /// assert_eq!(model.normals.len(), model.positions.len());
+    /// ```
+    pub fn generate_normals(&mut self, mode: NormalMode) {
+        match mode {
+            NormalMode::Smooth => self.generate_smooth_normals(),
+            NormalMode::Flat => self.generate_flat_normals(),
+        }
+    }
+
+    /// Checks that every index points to a vertex, and that normals and texture coordinates, if any, exist for
+    /// every vertex. Done before normals are generated, which would otherwise panic on malformed files.
+    pub(crate) fn validate(&self) -> Result<(), ModelLoadError> {
+        let vertex_count = self.positions.len();
+
+        if let Some(index) = self
+            .indices
+            .iter()
+            .find(|index| *index as usize >= vertex_count)
+        {
+            return Err(ModelLoadError::IndexOutOfRange {
+                index,
+                vertex_count,
+            });
+        }
+        if !self.normals.is_empty() && self.normals.len() != vertex_count {
+            return Err(ModelLoadError::MalformedNormals);
+        }
+        if !self.tex_coords.is_empty() && self.tex_coords.len() != vertex_count {
+            return Err(ModelLoadError::MalformedTexCoords);
+        }
+
+        Ok(())
+    }
+
+    /// Position of vertex as vector.
+    fn position(&self, index: u32) -> Vector3 {
+        let [x, y, z] = self.positions[index as usize].position;
+        Vector3::new(x, y, z)
+    }
+
+    fn generate_smooth_normals(&mut self) {
+        let mut normals = vec![Vector3::new(0.0, 0.0, 0.0); self.positions.len()];
+        let indices: Vec<u32> = self.indices.iter().collect();
+
+        for triangle in indices.chunks_exact(3) {
+            let corners = [
+                self.position(triangle[0]),
+                self.position(triangle[1]),
+                self.position(triangle[2]),
+            ];
+            let face_normal = (corners[1] - corners[0])
+                .cross_product(corners[2] - corners[0])
+                .normalize();
+
+            for i in 0..3 {
+                let to_next = (corners[(i + 1) % 3] - corners[i]).normalize();
+                let to_previous = (corners[(i + 2) % 3] - corners[i]).normalize();
+                let angle = to_next.dot(to_previous).clamp(-1.0, 1.0).acos();
+
+                normals[triangle[i] as usize] += face_normal * angle;
+            }
+        }
+
+        self.normals = normals
+            .into_iter()
+            .map(|normal| Normal {
+                normal: normal.normalize().to_array(),
+            })
+            .collect();
+    }
+
+    fn generate_flat_normals(&mut self) {
+        let indices: Vec<u32> = self.indices.iter().collect();
+        let has_tex_coords = !self.tex_coords.is_empty();
+
+        let mut positions = Vec::with_capacity(indices.len());
+        let mut normals = Vec::with_capacity(indices.len());
+        let mut tex_coords = Vec::with_capacity(indices.len());
+
+        for triangle in indices.chunks_exact(3) {
+            let corners = [
+                self.position(triangle[0]),
+                self.position(triangle[1]),
+                self.position(triangle[2]),
+            ];
+            let face_normal = (corners[1] - corners[0])
+                .cross_product(corners[2] - corners[0])
+                .normalize();
+
+            for index in triangle {
+                positions.push(self.positions[*index as usize]);
+                normals.push(Normal {
+                    normal: face_normal.to_array(),
+                });
+
+                if has_tex_coords {
+                    tex_coords.push(self.tex_coords[*index as usize]);
+                }
+            }
+        }
+
+        self.indices = Indices::from((0..positions.len() as u32).collect::<Vec<u32>>());
+        self.positions = positions;
+        self.normals = normals;
+        self.tex_coords = tex_coords;
+    }
+}
+
+/// Triangle indices of a model. Models whose indices all fit into u16 use the compact format, larger models use
+/// u32 indices.
+#[derive(Clone, Debug)]
+pub enum Indices {
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+}
+
+impl Indices {
+    pub fn len(&self) -> usize {
+        match self {
+            Indices::U16(indices) => indices.len(),
+            Indices::U32(indices) => indices.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over the indices as u32 regardless of the stored format.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = u32> + '_> {
+        match self {
+            Indices::U16(indices) => Box::new(indices.iter().map(|index| *index as u32)),
+            Indices::U32(indices) => Box::new(indices.iter().copied()),
+        }
+    }
+}
+
+impl From<Vec<u32>> for Indices {
+    /// Picks the u16 format if every index fits into it.
+    fn from(indices: Vec<u32>) -> Self {
+        if indices.iter().all(|index| *index <= u16::MAX as u32) {
+            Indices::U16(indices.into_iter().map(|index| index as u16).collect())
+        } else {
+            Indices::U32(indices)
+        }
+    }
+}
+
+impl From<Vec<u16>> for Indices {
+    fn from(indices: Vec<u16>) -> Self {
+        Indices::U16(indices)
+    }
+}
+
+/// Error returned by `load_obj()` and the glTF loader.
 ///
-/// # Examples
-/// ```
-/// let model = get_obj("path/to/the/model.obj");
-/// ```
-pub fn get_obj(path: &str) -> Model {
-    let (models, _materials) = tobj::load_obj(
-        path,
-        &tobj::LoadOptions {
-            single_index: true,
-            ..Default::default()
-        },
-    )
-    .expect(format!("Failed to load or parse provided obj: {}", path).as_str());
+/// # Variants
+/// - Obj: The file could not be opened or parsed. Wraps the error of the `tobj` crate.
+/// - Gltf: The glTF file could not be opened or parsed. Wraps the error of the `gltf` crate.
+/// - NoMeshes: The file does not contain any meshes.
+/// - MalformedPositions: The number of vertex position components is not divisible by three.
+/// - MissingPositions: A glTF primitive has no vertex positions.
+/// - MalformedNormals: The number of normal components is not divisible by three.
+/// - MalformedTexCoords: The number of texture coordinate components is not divisible by two.
+/// - IndexOutOfRange: A triangle index points past the last vertex of the mesh. Holds the index and the number of
+///   vertices.
+/// - Texture: A texture referenced by the MTL file could not be loaded.
+/// - UnsupportedPrimitive: A glTF primitive is made of points or lines instead of triangles.
+/// - UnsupportedTextureFormat: A glTF texture uses 16 bit or floating point pixels.
+#[derive(Debug)]
+pub enum ModelLoadError {
+    Obj(tobj::LoadError),
+    Gltf(gltf::Error),
+    NoMeshes,
+    MalformedPositions,
+    MissingPositions,
+    MalformedNormals,
+    MalformedTexCoords,
+    IndexOutOfRange { index: u32, vertex_count: usize },
+    Texture(image::ImageError),
+    UnsupportedPrimitive,
+    UnsupportedTextureFormat,
+}
+
+impl fmt::Display for ModelLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModelLoadError::Obj(error) => write!(f, "Failed to load or parse obj: {}", error),
+            ModelLoadError::Gltf(error) => write!(f, "Failed to load or parse gltf: {}", error),
+            ModelLoadError::NoMeshes => write!(f, "Model file does not contain any meshes"),
+            ModelLoadError::MalformedPositions => write!(f, "Vertexes were parsed invalidly"),
+            ModelLoadError::MissingPositions => write!(f, "Mesh has no vertex positions"),
+            ModelLoadError::MalformedNormals => write!(f, "Normals were parsed invalidly"),
+            ModelLoadError::MalformedTexCoords => {
+                write!(f, "Texture coordinates were parsed invalidly")
+            }
+            ModelLoadError::IndexOutOfRange {
+                index,
+                vertex_count,
+            } => write!(
+                f,
+                "Index {} is out of range of the {} vertices of the mesh",
+                index, vertex_count
+            ),
+            ModelLoadError::Texture(error) => write!(f, "Failed to load texture: {}", error),
+            ModelLoadError::UnsupportedPrimitive => write!(f, "Only triangle meshes are supported"),
+            ModelLoadError::UnsupportedTextureFormat => {
+                write!(f, "Only 8 bit textures are supported")
+            }
+        }
+    }
+}
+
+impl Error for ModelLoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ModelLoadError::Obj(error) => Some(error),
+            ModelLoadError::Gltf(error) => Some(error),
+            ModelLoadError::Texture(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<tobj::LoadError> for ModelLoadError {
+    fn from(error: tobj::LoadError) -> Self {
+        ModelLoadError::Obj(error)
+    }
+}
+
+impl From<gltf::Error> for ModelLoadError {
+    fn from(error: gltf::Error) -> Self {
+        ModelLoadError::Gltf(error)
+    }
+}
+
+impl From<image::ImageError> for ModelLoadError {
+    fn from(error: image::ImageError) -> Self {
+        ModelLoadError::Texture(error)
+    }
+}
 
-    let model = &models[0];
+/// Loads a texture referenced by a model file, given its resolved path. The `AssetServer` passes one that shares the
+/// textures between models, the loader functions load every texture on their own.
+pub(crate) type TextureLoader<'a> =
+    &'a mut dyn FnMut(&Path) -> Result<Handle<Texture>, ModelLoadError>;
 
+/// Loads the texture without sharing it.
+pub(crate) fn load_texture_unshared(path: &Path) -> Result<Handle<Texture>, ModelLoadError> {
+    Ok(Texture::from_path(path)?.into())
+}
+
+/// Converts a MTL material parsed by `tobj` into a `Material`. Texture paths are relative to `directory`.
+///
+/// Only the diffuse texture `map_Kd` becomes the albedo texture. The shader has no other texture slots, so ambient,
+/// specular, bump and normal maps are not loaded at all. Materials without `Ns` get `tobj`'s shininess 0, which
+/// would light the whole surface with the specular color, so they get the shininess of `Material::new()` instead.
+fn convert_material(
+    material: &tobj::Material,
+    directory: &Path,
+    load_texture: TextureLoader,
+) -> Result<Material, ModelLoadError> {
+    let albedo_texture = if material.diffuse_texture.is_empty() {
+        None
+    } else {
+        Some(load_texture(&directory.join(&material.diffuse_texture))?)
+    };
+
+    Ok(Material {
+        ambient_color: material.ambient.into(),
+        diffuse_color: material.diffuse.into(),
+        specular_color: material.specular.into(),
+        shininess: if material.shininess > 0.0 {
+            material.shininess
+        } else {
+            DEFAULT_SHININESS
+        },
+        dissolve: material.dissolve,
+        albedo_texture,
+    })
+}
+
+/// Converts a single mesh parsed by `tobj` into a `Model`.
+// `usize::is_multiple_of()` needs Rust 1.87, the remainder checks keep older compilers working.
+#[allow(clippy::manual_is_multiple_of)]
+fn convert_mesh(model: &tobj::Model, materials: &[Material]) -> Result<Model, ModelLoadError> {
     let m_vtx = &model.mesh.positions;
     let m_nor = &model.mesh.normals;
+    let m_tex = &model.mesh.texcoords;
     let m_ind = &model.mesh.indices;
 
     let mut vertexes: Vec<Vertex> = vec![];
     let mut normals: Vec<Normal> = vec![];
-    let mut indices: Vec<u16> = vec![];
-
-    assert!(
-        m_vtx.len() % 3 == 0,
-        "Vertexes of object: {}, was parsed invalidly.",
-        path
-    );
-    assert!(
-        m_nor.len() % 3 == 0,
-        "Normals of object: {}, was parsed invalidly.",
-        path
-    );
+    let mut tex_coords: Vec<TexCoord> = vec![];
+
+    if m_vtx.len() % 3 != 0 {
+        return Err(ModelLoadError::MalformedPositions);
+    }
+    if m_nor.len() % 3 != 0 {
+        return Err(ModelLoadError::MalformedNormals);
+    }
+    if m_tex.len() % 2 != 0 {
+        return Err(ModelLoadError::MalformedTexCoords);
+    }
 
     for vtx in 0..m_vtx.len() / 3 {
         vertexes.push(Vertex {
@@ -74,24 +355,174 @@ pub fn get_obj(path: &str) -> Model {
         });
     }
 
-    for nor in 0..model.mesh.normals.len() / 3 {
+    for nor in 0..m_nor.len() / 3 {
         normals.push(Normal {
             normal: [m_nor[3 * nor], m_nor[3 * nor + 1], m_nor[3 * nor + 2]],
         })
     }
 
-    for ind in m_ind {
-        assert!(
-            *ind >= u16::MIN.into() && *ind <= u16::MAX.into(),
-            "Failed to parse obj {}, file is too large!",
-            path
-        );
-        indices.push(*ind as u16);
+    for tex in 0..m_tex.len() / 2 {
+        tex_coords.push(TexCoord {
+            tex_coords: [m_tex[2 * tex], m_tex[2 * tex + 1]],
+        })
     }
 
-    Model {
+    let mut model = Model {
+        name: model.name.clone(),
         positions: vertexes,
         normals,
-        indices,
+        tex_coords,
+        indices: Indices::from(m_ind.clone()),
+        material: model
+            .mesh
+            .material_id
+            .and_then(|id| materials.get(id))
+            .cloned(),
+    };
+
+    model.validate()?;
+
+    if model.normals.is_empty() {
+        model.generate_normals(NormalMode::Smooth);
+    }
+
+    Ok(model)
+}
+
+/// Parses every mesh of a wavefront OBJ file and the materials of its MTL file. A missing MTL file is not an error,
+/// the meshes are left without materials instead.
+fn parse_obj(
+    path: &str,
+    load_texture: TextureLoader,
+) -> Result<(Vec<tobj::Model>, Vec<Material>), ModelLoadError> {
+    let path = resolve(path);
+    let (models, materials) = tobj::load_obj(
+        &path,
+        &tobj::LoadOptions {
+            single_index: true,
+            ..Default::default()
+        },
+    )?;
+
+    let materials = match materials {
+        Ok(materials) => materials,
+        Err(tobj::LoadError::OpenFileFailed) => vec![],
+        Err(error) => return Err(error.into()),
+    };
+
+    let directory = path.parent().unwrap_or_else(|| Path::new(""));
+    let materials = materials
+        .iter()
+        .map(|material| convert_material(material, directory, load_texture))
+        .collect::<Result<Vec<Material>, ModelLoadError>>()?;
+
+    Ok((models, materials))
+}
+
+/// loads and parses the first mesh of wavefront OBJ file into copper engine compatible format. Use
+/// `load_obj_meshes()` to load files with several objects. The material of the mesh is read from the MTL file
+/// referenced by the OBJ file. The path is resolved with `assets::resolve()`.
+///
+/// # Errors
+/// - `ModelLoadError::Obj`: Object parsing is failed, also when a face points to a vertex that does not exist
+/// - `ModelLoadError::NoMeshes`: File does not contain any meshes
+/// - `ModelLoadError::MalformedPositions`: Vertexes have been parsed invalidly
+/// - `ModelLoadError::MalformedNormals`: Normals have been parsed invalidly
+/// - `ModelLoadError::MalformedTexCoords`: Texture coordinates have been parsed invalidly
+/// - `ModelLoadError::IndexOutOfRange`: An index points to a vertex that does not exist
+/// - `ModelLoadError::Texture`: Texture of the material could not be loaded
+///
+/// # Examples
+/// ```
+/// use copper_engine::object::model_loader::{load_obj, ModelLoadError};
+///
+/// match load_obj("models/ico.obj") {
+///     Ok(model) => println!("Loaded {} vertices", model.positions.len()),
+///     Err(error) => eprintln!("Skipping broken model: {}", error),
+/// }
+///
+/// // The face of the triangle points to a sixth vertex.
+/// assert!(matches!(load_obj("models/broken_indices.obj"), Err(ModelLoadError::Obj(_))));
+/// ```
+pub fn load_obj(path: &str) -> Result<Model, ModelLoadError> {
+    load_obj_with(path, &mut load_texture_unshared)
+}
+
+/// Same as `load_obj()`, loading the textures of the materials with `load_texture`.
+pub(crate) fn load_obj_with(
+    path: &str,
+    load_texture: TextureLoader,
+) -> Result<Model, ModelLoadError> {
+    let (models, materials) = parse_obj(path, load_texture)?;
+    let model = models.first().ok_or(ModelLoadError::NoMeshes)?;
+
+    convert_mesh(model, &materials)
+}
+
+/// loads and parses every mesh of wavefront OBJ file into copper engine compatible format. Each mesh keeps the
+/// object name it has in the file and gets its own material from the MTL file.
+///
+/// # Errors
+/// Same as `load_obj()`. Fails if any of the meshes is invalid.
+///
+/// # Examples
+/// ```
+/// use copper_engine::object::model_loader::load_obj_meshes;
+///
+/// let meshes = load_obj_meshes("models/ico.obj").unwrap();
+///
+/// for mesh in &meshes {
+///     println!("{}: {} vertices", mesh.name, mesh.positions.len());
+/// }
+/// ```
+///
+/// Materials come from the MTL file next to the OBJ file. Only `map_Kd` is loaded as texture:
+/// ```
+/// use copper_engine::math::Vector3;
+/// use copper_engine::object::model_loader::load_obj_meshes;
+///
+/// let meshes = load_obj_meshes("models/crate.obj").unwrap();
+/// let names: Vec<&str> = meshes.iter().map(|mesh| mesh.name.as_str()).collect();
+/// assert_eq!(names, ["Lid", "Base"]);
+///
+/// // Wood has a diffuse, a specular and a bump map, the diffuse map becomes the albedo texture.
+/// let wood = meshes[0].material.as_ref().unwrap();
+/// assert_eq!(wood.ambient_color, Vector3::new(0.2, 0.1, 0.05));
+/// assert_eq!(wood.diffuse_color, Vector3::new(0.8, 0.4, 0.2));
+/// assert_eq!(wood.specular_color, Vector3::new(0.5, 0.5, 0.5));
+/// assert_eq!(wood.shininess, 64.0);
+/// assert!(wood.albedo_texture.is_some());
+///
+/// // Paint has no `Ns`, so it gets the shininess of `Material::new()` instead of 0.
+/// let paint = meshes[1].material.as_ref().unwrap();
+/// assert_eq!(paint.shininess, 16.0);
+/// assert_eq!(paint.dissolve, 0.5);
+/// assert!(paint.albedo_texture.is_none());
+/// assert_eq!(meshes[1].tex_coords.len(), 0);
+/// ```
+pub fn load_obj_meshes(path: &str) -> Result<Vec<Model>, ModelLoadError> {
+    let (models, materials) = parse_obj(path, &mut load_texture_unshared)?;
+
+    if models.is_empty() {
+        return Err(ModelLoadError::NoMeshes);
     }
+
+    models
+        .iter()
+        .map(|model| convert_mesh(model, &materials))
+        .collect()
+}
+
+/// loads and parses wavefront OBJ file into copper engine compatible format.
+///
+/// Panics if the model can not be loaded. Use `load_obj()` to handle the errors instead.
+///
+/// # Examples
+/// ```
+/// use copper_engine::object::model_loader::get_obj;
+///
+/// let model = get_obj("models/ico.obj");
+/// ```
+pub fn get_obj(path: &str) -> Model {
+    load_obj(path).unwrap_or_else(|error| panic!("Failed to load obj {}: {}", path, error))
 }
@@ -0,0 +1,369 @@
+//! Procedurally generated models. Primitives are centered on the origin, have outward facing normals and
+//! counter-clockwise front faces like models loaded with `model_loader::get_obj()`.
+
+use std::f32::consts::PI;
+
+use super::{
+    model_loader::{Indices, Model},
+    vertex::{Normal, TexCoord, Vertex},
+};
+use crate::math::Vector3;
+
+/// Collects vertices and triangles of a primitive.
+struct MeshBuilder {
+    positions: Vec<Vertex>,
+    normals: Vec<Normal>,
+    tex_coords: Vec<TexCoord>,
+    indices: Vec<u32>,
+}
+
+impl MeshBuilder {
+    fn new() -> Self {
+        Self {
+            positions: vec![],
+            normals: vec![],
+            tex_coords: vec![],
+            indices: vec![],
+        }
+    }
+
+    /// Adds vertex and returns its index.
+    fn vertex(&mut self, position: Vector3, normal: Vector3, tex_coords: [f32; 2]) -> u32 {
+        self.positions.push(Vertex {
+            position: position.to_array(),
+        });
+        self.normals.push(Normal {
+            normal: normal.normalize().to_array(),
+        });
+        self.tex_coords.push(TexCoord { tex_coords });
+
+        self.positions.len() as u32 - 1
+    }
+
+    /// Adds triangle with counter-clockwise corners.
+    fn triangle(&mut self, a: u32, b: u32, c: u32) {
+        self.indices.extend_from_slice(&[a, b, c]);
+    }
+
+    /// Adds quad with counter-clockwise corners as two triangles.
+    fn quad(&mut self, a: u32, b: u32, c: u32, d: u32) {
+        self.triangle(a, b, c);
+        self.triangle(a, c, d);
+    }
+
+    /// Adds disc facing up or down at height `y`.
+    fn cap(&mut self, radius: f32, y: f32, segments: u32, up: bool) {
+        let normal = Vector3::new(0.0, if up { 1.0 } else { -1.0 }, 0.0);
+        let center = self.vertex(Vector3::new(0.0, y, 0.0), normal, [0.5, 0.5]);
+
+        let first = self.positions.len() as u32;
+        for segment in 0..=segments {
+            let (sin, cos) = (2.0 * PI * segment as f32 / segments as f32).sin_cos();
+            self.vertex(
+                Vector3::new(radius * cos, y, -radius * sin),
+                normal,
+                [0.5 + 0.5 * cos, 0.5 + 0.5 * sin],
+            );
+        }
+
+        for segment in first..first + segments {
+            if up {
+                self.triangle(center, segment, segment + 1);
+            } else {
+                self.triangle(center, segment + 1, segment);
+            }
+        }
+    }
+
+    fn build(self, name: &str) -> Model {
+        Model {
+            name: String::from(name),
+            positions: self.positions,
+            normals: self.normals,
+            tex_coords: self.tex_coords,
+            indices: Indices::from(self.indices),
+            material: None,
+        }
+    }
+}
+
+impl Model {
+    /// creates cube with edges of length `size`. Every face has its own vertices and texture coordinates covering
+    /// the whole texture.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::object::model_loader::Model;
+    ///
+    /// let cube = Model::cube(2.0);
+    ///
+    /// assert_eq!(cube.positions.len(), 24);
+    /// assert_eq!(cube.indices.len(), 36);
+    /// ```
+    pub fn cube(size: f32) -> Self {
+        let mut builder = MeshBuilder::new();
+        let half = size / 2.0;
+
+        // Face normal followed by two axes on the face, `u` cross `v` equals the normal.
+        let faces = [
+            ([1.0, 0.0, 0.0], [0.0, 0.0, -1.0], [0.0, 1.0, 0.0]),
+            ([-1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0, 0.0]),
+            ([0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, -1.0]),
+            ([0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]),
+            ([0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]),
+            ([0.0, 0.0, -1.0], [-1.0, 0.0, 0.0], [0.0, 1.0, 0.0]),
+        ];
+
+        for ([nx, ny, nz], [ux, uy, uz], [vx, vy, vz]) in faces {
+            let normal = Vector3::new(nx, ny, nz);
+            let u = Vector3::new(ux, uy, uz);
+            let v = Vector3::new(vx, vy, vz);
+
+            let corner = |x: f32, y: f32| (normal + u * x + v * y) * half;
+
+            let a = builder.vertex(corner(-1.0, -1.0), normal, [0.0, 0.0]);
+            let b = builder.vertex(corner(1.0, -1.0), normal, [1.0, 0.0]);
+            let c = builder.vertex(corner(1.0, 1.0), normal, [1.0, 1.0]);
+            let d = builder.vertex(corner(-1.0, 1.0), normal, [0.0, 1.0]);
+            builder.quad(a, b, c, d);
+        }
+
+        builder.build("Cube")
+    }
+
+    /// creates flat plane on the xz-plane facing up. `width` is measured along the x-axis and `depth` along the
+    /// z-axis. Both sides are split into `subdivisions` segments, at least one.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::object::model_loader::Model;
+    ///
+    /// let plane = Model::plane(10.0, 10.0, 4);
+    ///
+    /// assert_eq!(plane.positions.len(), 25);
+    /// assert_eq!(plane.indices.len(), 96);
+    /// ```
+    pub fn plane(width: f32, depth: f32, subdivisions: u32) -> Self {
+        let mut builder = MeshBuilder::new();
+        let subdivisions = subdivisions.max(1);
+        let normal = Vector3::new(0.0, 1.0, 0.0);
+
+        for row in 0..=subdivisions {
+            for column in 0..=subdivisions {
+                let u = column as f32 / subdivisions as f32;
+                let v = row as f32 / subdivisions as f32;
+
+                builder.vertex(
+                    Vector3::new(width * (u - 0.5), 0.0, depth * (0.5 - v)),
+                    normal,
+                    [u, v],
+                );
+            }
+        }
+
+        let stride = subdivisions + 1;
+        for row in 0..subdivisions {
+            for column in 0..subdivisions {
+                let a = row * stride + column;
+                builder.quad(a, a + 1, a + stride + 1, a + stride);
+            }
+        }
+
+        builder.build("Plane")
+    }
+
+    /// creates sphere out of `rings` latitude bands, at least two, and `segments` longitude slices, at least three.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::object::model_loader::Model;
+    ///
+    /// let sphere = Model::uv_sphere(1.0, 16, 32);
+    ///
+    /// assert!(sphere.positions.iter().all(|vertex| {
+    ///     let [x, y, z] = vertex.position;
+    ///     ((x * x + y * y + z * z).sqrt() - 1.0).abs() < 1e-5
+    /// }));
+    /// ```
+    pub fn uv_sphere(radius: f32, rings: u32, segments: u32) -> Self {
+        let mut builder = MeshBuilder::new();
+        let rings = rings.max(2);
+        let segments = segments.max(3);
+
+        for ring in 0..=rings {
+            let (ring_sin, ring_cos) = (PI * ring as f32 / rings as f32).sin_cos();
+
+            for segment in 0..=segments {
+                let (sin, cos) = (2.0 * PI * segment as f32 / segments as f32).sin_cos();
+                let normal = Vector3::new(ring_sin * cos, ring_cos, -ring_sin * sin);
+
+                builder.vertex(
+                    normal * radius,
+                    normal,
+                    [
+                        segment as f32 / segments as f32,
+                        1.0 - ring as f32 / rings as f32,
+                    ],
+                );
+            }
+        }
+
+        let stride = segments + 1;
+        for ring in 0..rings {
+            for segment in 0..segments {
+                let a = ring * stride + segment;
+                let b = a + stride;
+
+                // Skip the triangles collapsing into the poles.
+                if ring != rings - 1 {
+                    builder.triangle(a, b, b + 1);
+                }
+                if ring != 0 {
+                    builder.triangle(a, b + 1, a + 1);
+                }
+            }
+        }
+
+        builder.build("UV Sphere")
+    }
+
+    /// creates closed cylinder standing on the y-axis. The side is made of `segments` slices, at least three.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::object::model_loader::Model;
+    ///
+    /// let cylinder = Model::cylinder(0.5, 2.0, 24);
+    /// ```
+    pub fn cylinder(radius: f32, height: f32, segments: u32) -> Self {
+        let mut builder = MeshBuilder::new();
+        let segments = segments.max(3);
+        let half = height / 2.0;
+
+        let first = builder.positions.len() as u32;
+        for segment in 0..=segments {
+            let u = segment as f32 / segments as f32;
+            let (sin, cos) = (2.0 * PI * u).sin_cos();
+            let normal = Vector3::new(cos, 0.0, -sin);
+
+            builder.vertex(
+                Vector3::new(radius * cos, half, -radius * sin),
+                normal,
+                [u, 1.0],
+            );
+            builder.vertex(
+                Vector3::new(radius * cos, -half, -radius * sin),
+                normal,
+                [u, 0.0],
+            );
+        }
+
+        for segment in 0..segments {
+            let top = first + 2 * segment;
+            builder.quad(top, top + 1, top + 3, top + 2);
+        }
+
+        builder.cap(radius, half, segments, true);
+        builder.cap(radius, -half, segments, false);
+
+        builder.build("Cylinder")
+    }
+
+    /// creates closed cone standing on the y-axis with its tip up. The side is made of `segments` slices, at least
+    /// three.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::object::model_loader::Model;
+    ///
+    /// let cone = Model::cone(0.5, 1.0, 24);
+    /// ```
+    pub fn cone(radius: f32, height: f32, segments: u32) -> Self {
+        let mut builder = MeshBuilder::new();
+        let segments = segments.max(3);
+        let half = height / 2.0;
+
+        let side_normal = |angle: f32| {
+            let (sin, cos) = angle.sin_cos();
+            Vector3::new(height * cos, radius, -height * sin)
+        };
+
+        for segment in 0..segments {
+            let start = 2.0 * PI * segment as f32 / segments as f32;
+            let end = 2.0 * PI * (segment + 1) as f32 / segments as f32;
+            let u = segment as f32 / segments as f32;
+            let next_u = (segment + 1) as f32 / segments as f32;
+
+            // Every slice gets its own tip so that the tip normal points between the slice edges.
+            let tip = builder.vertex(
+                Vector3::new(0.0, half, 0.0),
+                side_normal((start + end) / 2.0),
+                [(u + next_u) / 2.0, 1.0],
+            );
+            let left = builder.vertex(
+                Vector3::new(radius * start.cos(), -half, -radius * start.sin()),
+                side_normal(start),
+                [u, 0.0],
+            );
+            let right = builder.vertex(
+                Vector3::new(radius * end.cos(), -half, -radius * end.sin()),
+                side_normal(end),
+                [next_u, 0.0],
+            );
+
+            builder.triangle(tip, left, right);
+        }
+
+        builder.cap(radius, -half, segments, false);
+
+        builder.build("Cone")
+    }
+
+    /// creates torus lying on the xz-plane. `major_radius` is the distance from the center to the middle of the
+    /// tube and `minor_radius` the radius of the tube. The ring is made of `major_segments` and the tube of
+    /// `minor_segments` slices, at least three each.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::object::model_loader::Model;
+    ///
+    /// let torus = Model::torus(1.0, 0.25, 32, 16);
+    ///
+    /// assert_eq!(torus.indices.len(), 32 * 16 * 6);
+    /// ```
+    pub fn torus(
+        major_radius: f32,
+        minor_radius: f32,
+        major_segments: u32,
+        minor_segments: u32,
+    ) -> Self {
+        let mut builder = MeshBuilder::new();
+        let major_segments = major_segments.max(3);
+        let minor_segments = minor_segments.max(3);
+
+        for major in 0..=major_segments {
+            let u = major as f32 / major_segments as f32;
+            let (major_sin, major_cos) = (2.0 * PI * u).sin_cos();
+            let center = Vector3::new(major_radius * major_cos, 0.0, -major_radius * major_sin);
+
+            for minor in 0..=minor_segments {
+                let v = minor as f32 / minor_segments as f32;
+                let (minor_sin, minor_cos) = (2.0 * PI * v).sin_cos();
+                let normal = Vector3::new(minor_cos * major_cos, minor_sin, -minor_cos * major_sin);
+
+                builder.vertex(center + normal * minor_radius, normal, [u, v]);
+            }
+        }
+
+        let stride = minor_segments + 1;
+        for major in 0..major_segments {
+            for minor in 0..minor_segments {
+                let a = major * stride + minor;
+                let b = a + stride;
+                builder.quad(a, b, b + 1, a + 1);
+            }
+        }
+
+        builder.build("Torus")
+    }
+}
@@ -0,0 +1,97 @@
+//! Shader is the GLSL source of the program mesh renderers are drawn with. It is compiled once and shared by every
+//! renderer using it.
+
+use std::{cell::RefCell, fs};
+
+use glium::Program;
+
+use crate::assets::{handle::WeakHandle, resolve, Asset, Handle};
+use crate::error::EngineError;
+use crate::{FRAGMENT_SHADER_SRC, VERTEX_SHADER_SRC};
+
+thread_local! {
+    /// Built-in shader, kept loaded while any renderer uses it.
+    static STANDARD_SHADER: RefCell<WeakHandle<Shader>> = RefCell::new(WeakHandle::new());
+}
+
+/// # Fields
+/// - vertex_source: GLSL source of the vertex shader.
+/// - fragment_source: GLSL source of the fragment shader.
+///
+/// Custom shaders receive the same attributes and uniforms as the built-in shader, see the `shaders` directory of
+/// the engine. Usage of `::new()` or `::load()` is strongly recommended!
+#[derive(Clone, Debug)]
+pub struct Shader {
+    pub vertex_source: String,
+    pub fragment_source: String,
+}
+
+impl Shader {
+    /// creates shader from GLSL source.
+    pub fn new(vertex_source: &str, fragment_source: &str) -> Self {
+        Self {
+            vertex_source: String::from(vertex_source),
+            fragment_source: String::from(fragment_source),
+        }
+    }
+
+    /// loads GLSL source of the vertex and fragment shader from files. The paths are resolved with
+    /// `assets::resolve()`.
+    ///
+    /// # Errors
+    /// - `EngineError::ShaderIo`: One of the files could not be read
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::{object::Shader, EngineError};
+    ///
+    /// let shader = Shader::load("shaders/vertex_shader.glsl", "shaders/fragment_shader.glsl").unwrap();
+    ///
+    /// match Shader::load("shaders/missing.glsl", "shaders/fragment_shader.glsl") {
+    ///     Err(EngineError::ShaderIo { path, .. }) => assert!(path.ends_with("missing.glsl")),
+    ///     _ => panic!("missing shader file was read"),
+    /// }
+    /// ```
+    pub fn load(vertex_path: &str, fragment_path: &str) -> Result<Self, EngineError> {
+        Ok(Self {
+            vertex_source: read_shader(vertex_path)?,
+            fragment_source: read_shader(fragment_path)?,
+        })
+    }
+
+    /// Handle to the built-in shader. Every call returns the same handle while any of them is alive, so the program
+    /// is compiled only once.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::{assets::Handle, object::Shader};
+    ///
+    /// let shader = Shader::standard();
+    /// assert!(Handle::ptr_eq(&shader, &Shader::standard()));
+    /// ```
+    pub fn standard() -> Handle<Shader> {
+        STANDARD_SHADER.with(|standard| {
+            let mut standard = standard.borrow_mut();
+
+            standard.upgrade().unwrap_or_else(|| {
+                let shader = Handle::new(Shader::new(VERTEX_SHADER_SRC, FRAGMENT_SHADER_SRC));
+                *standard = shader.downgrade();
+                shader
+            })
+        })
+    }
+}
+
+/// Reads a shader source file.
+fn read_shader(path: &str) -> Result<String, EngineError> {
+    let path = resolve(path);
+
+    fs::read_to_string(&path).map_err(|error| EngineError::ShaderIo {
+        path: path.display().to_string(),
+        error,
+    })
+}
+
+impl Asset for Shader {
+    type Gpu = Program;
+}
@@ -0,0 +1,60 @@
+//! Texture is an image loaded into memory. The engine uploads it to the GPU once for every material sharing it.
+
+use std::path::Path;
+
+use glium::{texture::SrgbTexture2d, Display};
+use image::RgbaImage;
+
+use crate::assets::{resolve, Asset};
+use crate::error::EngineError;
+
+/// **Supported formats: png and jpeg.**
+/// # Fields
+/// - image: Decoded RGBA pixels of the texture.
+///
+/// Usage of `::load()` is strongly recommended!
+#[derive(Clone, Debug)]
+pub struct Texture {
+    pub image: RgbaImage,
+}
+
+impl Texture {
+    /// loads and decodes image file into texture. The path is resolved with `assets::resolve()`.
+    ///
+    /// # Errors
+    /// - i/o error
+    /// - Unsupported or corrupted image format
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::object::Texture;
+    ///
+    /// let texture = Texture::load("textures/debug_map.png").unwrap();
+    /// ```
+    pub fn load(path: &str) -> Result<Self, image::ImageError> {
+        Self::from_path(&resolve(path))
+    }
+
+    /// loads and decodes image file at an already resolved path.
+    pub(crate) fn from_path(path: &Path) -> Result<Self, image::ImageError> {
+        Ok(Self {
+            image: image::open(path)?.to_rgba8(),
+        })
+    }
+}
+
+impl Asset for Texture {
+    type Gpu = SrgbTexture2d;
+}
+
+/// Uploads texture to the GPU.
+pub(crate) fn upload_texture(
+    display: &Display,
+    texture: &Texture,
+) -> Result<SrgbTexture2d, EngineError> {
+    let image = &texture.image;
+    let image =
+        glium::texture::RawImage2d::from_raw_rgba_reversed(image.as_raw(), image.dimensions());
+
+    Ok(SrgbTexture2d::new(display, image)?)
+}
@@ -14,4 +14,12 @@ pub struct Normal {
     pub normal: [f32; 3],
 }
 
-implement_vertex!(Normal, normal);
\ No newline at end of file
+implement_vertex!(Normal, normal);
+
+/// Single \[u, v] texture coordinate.
+#[derive(Clone, Copy, Debug)]
+pub struct TexCoord {
+    pub tex_coords: [f32; 2],
+}
+
+implement_vertex!(TexCoord, tex_coords);
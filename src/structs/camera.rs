@@ -1,6 +1,6 @@
 //! Camera is the main component responsible of rendering objects to the screen. Cameras are also the main audio listener of the scene.
 
-use crate::math::{Matrix4x4, Vector3};
+use crate::math::{EulerAngle, LookAtCoordinate, Matrix4x4, UVector2, Vector3};
 
 use super::scene::Scene;
 
@@ -9,10 +9,10 @@ use super::scene::Scene;
 /// - z_far: Distance of the far clipping plane on z-axis.
 /// - fov: Field of view of the camera in **radian**.
 /// - position: Set of \[x, y, z] coordinates indicating the translation of the camera form the world origin (0, 0, 0).
-/// - rotation: Set of \[x, y, z] f32. Indicates camera's rotation in **radian**.
+/// - rotation: Point in world space the camera looks at, see `LookAtCoordinate`. Use `set_euler_angle()` to turn the camera by yaw and pitch instead.
 /// - scale: Set of \[x, y, z] f32. Indicates the scale of the camera on each axis.
 /// - up_vector: Vector that points up usually set to `[0, 1, 0]`.
-/// - tick_update_func: `tick_update_func` is called every drawn frame. For more info about function call order refer to github wiki pages.
+/// - tick_update_func: `tick_update_func` is called every drawn frame. For more info about function call order refer to github wiki pages. Stateful camera scripts are added with `Scene::add_camera_behaviour()`.
 /// - on_awake: `on_awake` is called once after model loading is completed.
 ///
 /// Usage of `::new()` is strongly recommended.
@@ -28,7 +28,7 @@ pub struct Camera {
     pub right_vector: Vector3,
     pub tick_update_func: fn(&mut Scene),
     pub on_awake: fn(&mut Scene),
-    pub window_size: [u32; 2],
+    pub window_size: UVector2,
     pub cam_dir: Vector3,
     pub local_up: Vector3,
     pub local_right: Vector3,
@@ -70,61 +70,47 @@ impl Camera {
             right_vector: Vector3::new(1.0, 0.0, 0.0),
             tick_update_func: tick_update_func,
             on_awake: on_awake,
-            window_size: [0, 0],
+            window_size: UVector2::new(0, 0),
             cam_dir: Vector3::new(0.0, 0.0, 0.0),
             local_up: Vector3::new(0.0, 0.0, 0.0),
             local_right: Vector3::new(0.0, 0.0, 0.0),
         }
     }
 
-    pub fn look_at(&mut self) -> Matrix4x4 {
-        let position: glm::Vector3<f32> = glm::Vector3 {
-            x: self.position.x,
-            y: self.position.y,
-            z: self.position.z,
-        };
-
-        let target = glm::Vector3 {
-            x: self.rotation.x,
-            y: self.rotation.y,
-            z: self.rotation.z,
-        };
-        let up = glm::Vector3 {
-            x: self.up_vector.x,
-            y: self.up_vector.y,
-            z: self.up_vector.z,
-        };
-
-        // let cam_dir = (position - target).normalize();
-        // let cam_right = cam_dir.cross_product(up).normalize();
-        // let cam_up = cam_right.cross_product(cam_dir);
-
-        // self.cam_dir = cam_dir;
-        // self.local_right = cam_right;
-        // self.local_up = cam_up;
-
-        // let rotation_mat = Matrix4x4::new(
-        //     [cam_right.x, cam_right.y, cam_right.z, 0.0],
-        //     [cam_up.x, cam_up.y, cam_up.z, 0.0],
-        //     [cam_dir.x, cam_dir.y, cam_dir.z, 0.0],
-        //     [
-        //         -cam_dir.dot(position),
-        //         -cam_right.dot(position),
-        //         cam_up.dot(position),
-        //         1.0,
-        //     ],
-        // );
-
-        let look_mat = *glm::ext::look_at(position, target, up).as_array();
-
-        let mut view_matrix = Matrix4x4::empty();
+    /// Yaw and pitch of the direction from `position` towards `rotation`. The roll is 0.
+    pub fn euler_angle(&self) -> EulerAngle {
+        EulerAngle::from_look_at_coordinates(LookAtCoordinate::from(self.rotation), self.position)
+    }
 
-        for i in 0..4 {
-            for j in 0..4 {
-                view_matrix.matrix[i][j] = look_mat[i][j];
-            }
-        }
+    /// turns the camera to the yaw and pitch of `rotation` by placing the look at point, `rotation`, one unit in
+    /// front of `position`. The roll is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use copper_engine::{blank_on_awake, blank_tick_update, math::{EulerAngle, Vector3}, Camera};
+    ///
+    /// let mut camera = Camera::new(
+    ///     0.1,
+    ///     100.0,
+    ///     1.0,
+    ///     Vector3::new(0.0, 0.0, 10.0),
+    ///     Vector3::new(0.0, 0.0, 0.0),
+    ///     blank_tick_update,
+    ///     blank_on_awake,
+    /// );
+    ///
+    /// let mut rotation = camera.euler_angle();
+    /// rotation.yaw += 0.5;
+    /// camera.set_euler_angle(rotation);
+    ///
+    /// assert!((camera.euler_angle().yaw - 0.5).abs() < 1e-5);
+    /// ```
+    pub fn set_euler_angle(&mut self, rotation: EulerAngle) {
+        self.rotation = LookAtCoordinate::from_euler_angle(rotation, self.position).to_vector3();
+    }
 
-        view_matrix
+    /// View matrix of the camera looking from `position` at `rotation`, see `Matrix4x4::look_at()`.
+    pub fn look_at(&mut self) -> Matrix4x4 {
+        Matrix4x4::look_at(self.position, self.rotation, self.up_vector)
     }
 }
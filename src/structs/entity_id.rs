@@ -0,0 +1,19 @@
+//! Stable handle to an entity of a scene.
+
+use std::fmt;
+
+/// Identifies a spawned entity. Ids stay valid while other entities are spawned and despawned. Once the entity is
+/// despawned its id is stale, lookups with it return `None` even after the slot has been reused by a new entity.
+///
+/// Ids are created by `Scene::spawn()`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct EntityId {
+    pub(crate) index: u32,
+    pub(crate) generation: u32,
+}
+
+impl fmt::Display for EntityId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}v{}", self.index, self.generation)
+    }
+}
@@ -1,5 +1,8 @@
 pub mod camera;
+pub mod entity_id;
 pub mod scene;
+pub(crate) mod window_requests;
 
 pub use camera::Camera;
+pub use entity_id::EntityId;
 pub use scene::Scene;
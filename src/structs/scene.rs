@@ -1,33 +1,71 @@
 //! Scene is collection of everything copper engine needs to function.
 
+use crate::assets::AssetServer;
 use crate::audio::AudioSource;
-use crate::object::GameObject;
+use crate::ecs::{
+    storage::Components, Bundle, Hierarchy, Name, Query, QueryIter, RenderSystem, System,
+    WorldMatrix,
+};
 use crate::input::InputManager;
+use crate::math::{Matrix4x4, Quaternion, Transform, UVector2, Vector3};
+use crate::object::{mesh_renderer::MeshRendererSystem, Behaviour, Behaviours, GameObject};
+use crate::{blank_on_exit, WindowMode};
 
-use super::{camera::Camera, };
+use super::{camera::Camera, window_requests::WindowRequests, EntityId};
+
+/// Generation of an entity id, reused after the entity is despawned.
+struct EntitySlot {
+    generation: u32,
+    alive: bool,
+}
 
 /// # Fields
-/// - game_objects: List of currently loaded game objects.
 /// - audio_sources: List of all audio sources in scene.
-
 /// - input_manager: Main input manager.
 /// - main_camera: Main camera.
+/// - asset_server: Loads models, textures, sounds and shaders once and shares them, for example between objects spawned while the game runs.
 /// - delta_time: Time between last frame release and this instant in seconds.
 /// - time_since_start: Time since the program was started in seconds. Updated at start of every loop cycle.
+/// - fixed_delta_time: Time between two fixed steps in seconds, `1/60` by default. Fixed steps run `fixed_update_func`, `on_fixed_update` and fixed systems at the same rate on every machine.
+/// - max_fixed_steps: Most fixed steps run in one frame. If the frame took longer, the remaining time is dropped, so slow frames can not cause ever more fixed steps.
+/// - fixed_alpha: How far the current frame is between the last and the next fixed step, in range 0.0-1.0. Can be used to blend between the states of the last two fixed steps when drawing.
+/// - on_exit: `on_exit` is called once before the engine shuts down, whether the window was closed or `request_exit()` was called. Can be used to save the game.
+///
+/// Entities are added and removed with `spawn()` and `despawn()`, their components are accessed with `get()`,
+/// `get_mut()` and `query()`. See the `ecs` module.
+///
+/// The main camera has an entity of its own named "Main camera", `main_camera_entity()`, which holds the behaviours
+/// added with `add_camera_behaviour()`. It is spawned before the objects, so its behaviours are called first.
 ///
 /// Usage of `::new()` is strongly recommended!
 pub struct Scene {
-    pub game_objects: Vec<GameObject>,
     pub audio_sources: Vec<AudioSource>,
 
     pub input_manager: InputManager,
     pub main_camera: Camera,
+    pub asset_server: AssetServer,
     pub delta_time: f32,
     pub time_since_start: f32,
+    pub fixed_delta_time: f32,
+    pub max_fixed_steps: u32,
+    pub fixed_alpha: f32,
+    pub on_exit: fn(&mut Scene),
+
+    pub(crate) fixed_accumulator: f32,
+    components: Components,
+    entities: Vec<EntitySlot>,
+    free_entities: Vec<u32>,
+    pub(crate) systems: Vec<Box<dyn System>>,
+    pub(crate) fixed_systems: Vec<Box<dyn System>>,
+    pub(crate) render_systems: Vec<Box<dyn RenderSystem>>,
+    pub(crate) unawakened: Vec<EntityId>,
+    pub(crate) destroyed: Vec<(EntityId, Vec<Box<dyn Behaviour>>)>,
+    pub(crate) window_requests: WindowRequests,
+    camera_entity: EntityId,
 }
 
 impl Scene {
-    /// creates Scene
+    /// creates Scene. `objects` are spawned like with `spawn_batch()`.
     ///
     /// # Examples
     ///
@@ -41,13 +79,604 @@ impl Scene {
         input_manager: InputManager,
         main_camera: Camera,
     ) -> Self {
-        Self {
-            game_objects: objects,
+        let mut scene = Self {
             audio_sources,
             input_manager,
             main_camera,
+            asset_server: AssetServer::new(),
             delta_time: 0.0,
             time_since_start: 0.0,
+            fixed_delta_time: 1.0 / 60.0,
+            max_fixed_steps: 5,
+            fixed_alpha: 0.0,
+            on_exit: blank_on_exit,
+
+            fixed_accumulator: 0.0,
+            components: Components::new(),
+            entities: vec![],
+            free_entities: vec![],
+            systems: vec![],
+            fixed_systems: vec![],
+            render_systems: vec![Box::new(MeshRendererSystem)],
+            unawakened: vec![],
+            destroyed: vec![],
+            window_requests: WindowRequests::default(),
+            camera_entity: EntityId {
+                index: 0,
+                generation: 0,
+            },
+        };
+        scene.camera_entity =
+            scene.spawn((Name(String::from("Main camera")), Behaviours::default()));
+        scene.spawn_batch(objects);
+
+        scene
+    }
+
+    /// adds entity with the components of `bundle` to the scene and returns its id. Entities can be spawned at any
+    /// time, also from `tick_update_func` while the engine is running. Their models are uploaded to the GPU and
+    /// their `on_awake` is called before the next frame.
+    ///
+    /// # Examples
+    /// ```
+    /// # use copper_engine::{blank_on_awake, blank_tick_update, input::InputManager, math::Vector3, structs::Scene, Camera};
+    /// # let camera = Camera::new(0.1, 100.0, 1.0, Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), blank_tick_update, blank_on_awake);
+    /// use copper_engine::{ecs::Name, math::Transform, object::{model_loader::Model, GameObject, Material}};
+    ///
+    /// let mut scene = Scene::new(vec![], vec![], InputManager::new(), camera);
+    /// let cube = GameObject::new(
+    ///     "Cube",
+    ///     Model::cube(1.0),
+    ///     Transform::identity(),
+    ///     Material::new([0.5, 0.5, 0.5]),
+    ///     blank_tick_update,
+    ///     blank_on_awake,
+    /// );
+    ///
+    /// let id = scene.spawn(cube);
+    /// assert_eq!(scene.get::<Name>(id).unwrap().0, "Cube");
+    ///
+    /// scene.despawn(id);
+    /// assert!(scene.get::<Name>(id).is_none());
+    /// ```
+    pub fn spawn(&mut self, bundle: impl Bundle) -> EntityId {
+        let id = match self.free_entities.pop() {
+            Some(slot) => {
+                let entity = &mut self.entities[slot as usize];
+                entity.alive = true;
+
+                EntityId {
+                    index: slot,
+                    generation: entity.generation,
+                }
+            }
+            None => {
+                self.entities.push(EntitySlot {
+                    generation: 0,
+                    alive: true,
+                });
+
+                EntityId {
+                    index: self.entities.len() as u32 - 1,
+                    generation: 0,
+                }
+            }
+        };
+
+        self.insert(id, Hierarchy::default());
+        bundle.insert_into(self, id);
+        self.unawakened.push(id);
+
+        id
+    }
+
+    /// spawns game objects and returns their ids in the same order. Parent links between the objects, for example
+    /// those set by `gltf_loader::load_gltf_objects()`, are kept.
+    pub fn spawn_batch(&mut self, objects: Vec<GameObject>) -> Vec<EntityId> {
+        let parents: Vec<Option<usize>> =
+            objects.iter().map(|object| object.spawn_parent).collect();
+        let ids: Vec<EntityId> = objects
+            .into_iter()
+            .map(|object| self.spawn(object))
+            .collect();
+
+        for (&id, parent) in ids.iter().zip(parents) {
+            if let Some(parent) = parent {
+                self.set_parent(id, Some(ids[parent]), false);
+            }
+        }
+
+        ids
+    }
+
+    /// removes entity with all of its components and all of its children from the scene. `on_destroy` of their
+    /// behaviours is called at the end of the frame. Returns `false` if the id is stale.
+    pub fn despawn(&mut self, id: EntityId) -> bool {
+        if !self.contains(id) {
+            return false;
+        }
+
+        if let Some(parent) = self.parent(id) {
+            if let Some(parent) = self.get_mut::<Hierarchy>(parent) {
+                parent.children.retain(|&child| child != id);
+            }
+        }
+
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            if !self.contains(id) {
+                continue;
+            }
+
+            stack.extend_from_slice(self.children(id));
+
+            if let Some(Behaviours(behaviours)) = self.remove::<Behaviours>(id) {
+                if !behaviours.is_empty() {
+                    self.destroyed.push((id, behaviours));
+                }
+            }
+            self.components.remove_entity(id);
+
+            let entity = &mut self.entities[id.index as usize];
+            entity.alive = false;
+            entity.generation = entity.generation.wrapping_add(1);
+            self.free_entities.push(id.index);
+        }
+
+        true
+    }
+
+    /// Whether the entity is spawned, `false` once it has been despawned.
+    pub fn contains(&self, id: EntityId) -> bool {
+        self.entities
+            .get(id.index as usize)
+            .is_some_and(|entity| entity.alive && entity.generation == id.generation)
+    }
+
+    /// Ids of all spawned entities. This includes the entity of the main camera, `main_camera_entity()`, which every
+    /// scene spawns before the objects, so a new scene is not empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use copper_engine::{blank_on_awake, blank_tick_update, input::InputManager, math::Vector3, structs::Scene, Camera};
+    /// # let camera = Camera::new(0.1, 100.0, 1.0, Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), blank_tick_update, blank_on_awake);
+    /// use copper_engine::ecs::Name;
+    ///
+    /// let mut scene = Scene::new(vec![], vec![], InputManager::new(), camera);
+    /// let player = scene.spawn((Name(String::from("Player")),));
+    ///
+    /// let camera = scene.main_camera_entity();
+    /// assert_eq!(scene.entities().collect::<Vec<_>>(), [camera, player]);
+    ///
+    /// // Only the spawned objects.
+    /// let objects: Vec<_> = scene.entities().filter(|&id| id != camera).collect();
+    /// assert_eq!(objects, [player]);
+    /// ```
+    pub fn entities(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.entities
+            .iter()
+            .enumerate()
+            .filter(|(_, entity)| entity.alive)
+            .map(|(index, entity)| EntityId {
+                index: index as u32,
+                generation: entity.generation,
+            })
+    }
+
+    /// attaches component to the entity, replacing its component of the same type. Returns `false` without
+    /// attaching the component if the id is stale.
+    pub fn insert<T: 'static>(&mut self, id: EntityId, component: T) -> bool {
+        if !self.contains(id) {
+            return false;
+        }
+
+        self.components.storage_or_insert().insert(id, component);
+
+        true
+    }
+
+    /// detaches component of type `T` from the entity and returns it.
+    pub fn remove<T: 'static>(&mut self, id: EntityId) -> Option<T> {
+        self.components.storage_mut()?.remove(id)
+    }
+
+    /// Component of type `T` of the entity, `None` if the entity does not have one or has been despawned.
+    pub fn get<T: 'static>(&self, id: EntityId) -> Option<&T> {
+        self.components.storage()?.get(id)
+    }
+
+    /// Component of type `T` of the entity, `None` if the entity does not have one or has been despawned.
+    pub fn get_mut<T: 'static>(&mut self, id: EntityId) -> Option<&mut T> {
+        self.components.storage_mut()?.get_mut(id)
+    }
+
+    /// Whether the entity has a component of type `T`.
+    pub fn has<T: 'static>(&self, id: EntityId) -> bool {
+        self.get::<T>(id).is_some()
+    }
+
+    /// Iterates over every entity that has all components of the query. `Q` is `&T`, `&mut T` or a tuple of them.
+    ///
+    /// # Panics
+    /// Panics if a component type appears more than once in the query.
+    ///
+    /// # Examples
+    /// ```
+    /// # use copper_engine::{blank_on_awake, blank_tick_update, input::InputManager, structs::Scene, Camera};
+    /// # let camera = Camera::new(0.1, 100.0, 1.0, Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), blank_tick_update, blank_on_awake);
+    /// use copper_engine::math::{Transform, Vector3};
+    ///
+    /// struct Velocity(Vector3);
+    ///
+    /// let mut scene = Scene::new(vec![], vec![], InputManager::new(), camera);
+    /// let moving = scene.spawn((Transform::identity(), Velocity(Vector3::new(1.0, 0.0, 0.0))));
+    /// let still = scene.spawn((Transform::identity(),));
+    ///
+    /// for (_, (transform, velocity)) in scene.query::<(&mut Transform, &Velocity)>() {
+    ///     transform.translation = transform.translation + velocity.0;
+    /// }
+    ///
+    /// assert_eq!(scene.get::<Transform>(moving).unwrap().translation.to_array(), [1.0, 0.0, 0.0]);
+    /// assert_eq!(scene.get::<Transform>(still).unwrap().translation.to_array(), [0.0, 0.0, 0.0]);
+    /// ```
+    pub fn query<Q: Query>(&mut self) -> QueryIter<'_, Q> {
+        QueryIter::new(&mut self.components)
+    }
+
+    /// adds system to the end of the systems run every frame. Systems run after `on_update` of the behaviours and
+    /// before their `on_late_update`.
+    pub fn add_system(&mut self, system: impl System + 'static) {
+        self.systems.push(Box::new(system));
+    }
+
+    /// adds system to the end of the systems run every fixed step, after `fixed_update_func` and `on_fixed_update`
+    /// of every entity.
+    ///
+    /// # Examples
+    /// ```
+    /// # use copper_engine::{blank_on_awake, blank_tick_update, input::InputManager, math::Vector3, structs::Scene, Camera};
+    /// # let camera = Camera::new(0.1, 100.0, 1.0, Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), blank_tick_update, blank_on_awake);
+    /// use copper_engine::{engine_headless, object::FrameContext};
+    ///
+    /// struct Steps(u32);
+    ///
+    /// let mut scene = Scene::new(vec![], vec![], InputManager::new(), camera);
+    /// let counter = scene.spawn((Steps(0),));
+    /// scene.fixed_delta_time = 1.0 / 120.0;
+    /// scene.add_fixed_system(|scene: &mut Scene, _context: &FrameContext| {
+    ///     for (_, steps) in scene.query::<&mut Steps>() {
+    ///         steps.0 += 1;
+    ///     }
+    /// });
+    ///
+    /// // One second at 60 frames per second.
+    /// engine_headless(&mut scene, 60);
+    ///
+    /// assert_eq!(scene.get::<Steps>(counter).unwrap().0, 120);
+    /// ```
+    pub fn add_fixed_system(&mut self, system: impl System + 'static) {
+        self.fixed_systems.push(Box::new(system));
+    }
+
+    /// adds system to the end of the render systems, which draw every frame after the world matrices are updated.
+    /// The first render system of every scene is the `MeshRendererSystem`, so added render systems draw over the
+    /// meshes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use copper_engine::{blank_on_awake, blank_tick_update, input::InputManager, math::Vector3, structs::Scene, Camera};
+    /// # let camera = Camera::new(0.1, 100.0, 1.0, Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), blank_tick_update, blank_on_awake);
+    /// use copper_engine::{ecs::RenderContext, EngineError};
+    /// use glium::Surface;
+    ///
+    /// let mut scene = Scene::new(vec![], vec![], InputManager::new(), camera);
+    ///
+    /// // Clears the depth buffer after the meshes, so render systems added later draw on top of them.
+    /// scene.add_render_system(|_scene: &mut Scene, context: &mut RenderContext| -> Result<(), EngineError> {
+    ///     context.target.clear_depth(1.0);
+    ///     Ok(())
+    /// });
+    /// ```
+    pub fn add_render_system(&mut self, system: impl RenderSystem + 'static) {
+        self.render_systems.push(Box::new(system));
+    }
+
+    /// attaches behaviour to the entity after its other behaviours. Behaviours added to an entity that is already
+    /// awake do not receive `on_awake`. Returns `false` if the id is stale.
+    pub fn add_behaviour(&mut self, id: EntityId, behaviour: impl Behaviour + 'static) -> bool {
+        if !self.contains(id) {
+            return false;
+        }
+
+        match self.get_mut::<Behaviours>(id) {
+            Some(behaviours) => behaviours.0.push(Box::new(behaviour)),
+            None => {
+                self.insert(id, Behaviours(vec![Box::new(behaviour)]));
+            }
+        }
+
+        true
+    }
+
+    /// Id of the entity holding the behaviours of the main camera. Stale if the entity has been despawned.
+    pub fn main_camera_entity(&self) -> EntityId {
+        self.camera_entity
+    }
+
+    /// attaches stateful script to the main camera, see `Behaviour`. The callbacks get the id of
+    /// `main_camera_entity()` and move the camera through `scene.main_camera`. Returns `false` if the camera entity
+    /// has been despawned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use copper_engine::{blank_on_awake, blank_tick_update, input::InputManager, math::Vector3, structs::Scene, Camera};
+    /// # let camera = Camera::new(0.1, 100.0, 1.0, Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), blank_tick_update, blank_on_awake);
+    /// use copper_engine::{engine_headless, object::{Behaviour, FrameContext}, structs::EntityId};
+    ///
+    /// // Moves the camera forward, faster every frame.
+    /// struct Accelerate {
+    ///     speed: f32,
+    /// }
+    ///
+    /// impl Behaviour for Accelerate {
+    ///     fn on_update(&mut self, scene: &mut Scene, _id: EntityId, context: &FrameContext) {
+    ///         self.speed += 1.0;
+    ///         scene.main_camera.position.z -= self.speed * context.delta_time;
+    ///     }
+    /// }
+    ///
+    /// let mut scene = Scene::new(vec![], vec![], InputManager::new(), camera);
+    /// scene.add_camera_behaviour(Accelerate { speed: 0.0 });
+    ///
+    /// engine_headless(&mut scene, 3);
+    /// assert!((scene.main_camera.position.z + 6.0 / 60.0).abs() < 1e-6);
+    /// ```
+    pub fn add_camera_behaviour(&mut self, behaviour: impl Behaviour + 'static) -> bool {
+        self.add_behaviour(self.camera_entity, behaviour)
+    }
+
+    /// Id of the first entity named `name`. The entity of the main camera is named "Main camera", see `entities()`.
+    pub fn find(&self, name: &str) -> Option<EntityId> {
+        let names = self.components.storage::<Name>()?;
+
+        names
+            .ids()
+            .iter()
+            .copied()
+            .find(|&id| names.get(id).is_some_and(|entity| entity.0 == name))
+    }
+
+    /// Id of the parent of the entity, `None` for root entities and stale ids.
+    pub fn parent(&self, id: EntityId) -> Option<EntityId> {
+        self.get::<Hierarchy>(id)?.parent
+    }
+
+    /// Ids of the direct children of the entity. Empty if the id is stale.
+    pub fn children(&self, id: EntityId) -> &[EntityId] {
+        match self.get::<Hierarchy>(id) {
+            Some(hierarchy) => &hierarchy.children,
+            None => &[],
+        }
+    }
+
+    /// Ids of the entities without parent, including the entity of the main camera, see `entities()`.
+    pub fn roots(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.entities().filter(|&id| self.parent(id).is_none())
+    }
+
+    /// makes `parent` the parent of `child`, or `child` a root entity if `parent` is `None`. With
+    /// `keep_world_transform` the local transform of the child is changed so that it stays where it is in the
+    /// world, otherwise the local transform is kept and the child moves along with its new parent. Returns `false`
+    /// without changing anything if either id is stale or if `child` is `parent` or one of its ancestors, which would
+    /// make a cycle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use copper_engine::{blank_on_awake, blank_tick_update, input::InputManager, structs::Scene, Camera};
+    /// # let camera = Camera::new(0.1, 100.0, 1.0, Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), blank_tick_update, blank_on_awake);
+    /// use copper_engine::{math::{Transform, Vector3}, object::{model_loader::Model, GameObject, Material}};
+    ///
+    /// let object = |name: &str, translation: Vector3| GameObject::new(
+    ///     name,
+    ///     Model::cube(1.0),
+    ///     Transform::from_translation(translation),
+    ///     Material::new([0.5, 0.5, 0.5]),
+    ///     blank_tick_update,
+    ///     blank_on_awake,
+    /// );
+    /// let mut scene = Scene::new(vec![], vec![], InputManager::new(), camera);
+    /// let tank = scene.spawn(object("Tank", Vector3::new(10.0, 0.0, 0.0)));
+    /// let turret = scene.spawn(object("Turret", Vector3::new(0.0, 1.0, 0.0)));
+    ///
+    /// scene.set_parent(turret, Some(tank), false);
+    ///
+    /// assert_eq!(scene.children(tank), &[turret]);
+    /// assert_eq!(scene.world_position(turret).unwrap().to_array(), [10.0, 1.0, 0.0]);
+    ///
+    /// // The tank can not become a child of its own turret.
+    /// assert!(!scene.set_parent(tank, Some(turret), false));
+    /// assert!(!scene.set_parent(tank, Some(tank), false));
+    /// assert_eq!(scene.parent(tank), None);
+    ///
+    /// scene.set_parent(turret, None, true);
+    ///
+    /// assert_eq!(scene.get::<Transform>(turret).unwrap().translation.to_array(), [10.0, 1.0, 0.0]);
+    /// ```
+    pub fn set_parent(
+        &mut self,
+        child: EntityId,
+        parent: Option<EntityId>,
+        keep_world_transform: bool,
+    ) -> bool {
+        if !self.contains(child) || parent.is_some_and(|parent| !self.contains(parent)) {
+            return false;
+        }
+
+        let mut ancestor = parent;
+        while let Some(id) = ancestor {
+            if id == child {
+                return false;
+            }
+            ancestor = self.parent(id);
+        }
+
+        if keep_world_transform && self.has::<Transform>(child) {
+            let world = self.world_transform(child).unwrap();
+            let parent_world = parent.and_then(|parent| self.world_transform(parent));
+
+            *self.get_mut::<Transform>(child).unwrap() = match parent_world {
+                Some(parent_world) => world * parent_world.inverse(),
+                None => world,
+            };
+        }
+
+        if let Some(old_parent) = self.parent(child) {
+            if let Some(old_parent) = self.get_mut::<Hierarchy>(old_parent) {
+                old_parent.children.retain(|&id| id != child);
+            }
+        }
+        if let Some(parent) = parent {
+            self.get_mut::<Hierarchy>(parent)
+                .unwrap()
+                .children
+                .push(child);
+        }
+        self.get_mut::<Hierarchy>(child).unwrap().parent = parent;
+
+        true
+    }
+
+    /// Transform of the entity in world space, `None` if the id is stale. Entities without `Transform` count as not
+    /// transformed. Exact as long as no ancestor is both rotated and scaled non-uniformly, `world_matrix()` is exact
+    /// in every case.
+    pub fn world_transform(&self, id: EntityId) -> Option<Transform> {
+        if !self.contains(id) {
+            return None;
+        }
+
+        let local = |id| self.get::<Transform>(id).copied().unwrap_or_default();
+        let mut transform = local(id);
+
+        let mut ancestor = self.parent(id);
+        while let Some(parent) = ancestor {
+            transform = transform * local(parent);
+            ancestor = self.parent(parent);
+        }
+
+        Some(transform)
+    }
+
+    /// Translation of the entity in world space, `None` if the id is stale.
+    pub fn world_position(&self, id: EntityId) -> Option<Vector3> {
+        self.world_transform(id)
+            .map(|transform| transform.translation)
+    }
+
+    /// Rotation of the entity in world space, `None` if the id is stale.
+    pub fn world_rotation(&self, id: EntityId) -> Option<Quaternion> {
+        self.world_transform(id).map(|transform| transform.rotation)
+    }
+
+    /// Model matrix of the entity in world space, updated every frame before drawing. `None` for entities without
+    /// `Transform` and before the first frame of the entity.
+    pub fn world_matrix(&self, id: EntityId) -> Option<Matrix4x4> {
+        self.get::<WorldMatrix>(id).map(|matrix| matrix.0)
+    }
+
+    /// asks the engine to close the window and shut down after the current frame. `on_exit` is called before.
+    ///
+    /// # Examples
+    /// ```
+    /// # use copper_engine::{blank_on_awake, input::InputManager, math::Vector3, structs::Scene, Camera};
+    /// use copper_engine::engine_headless;
+    ///
+    /// fn quit_after_one_second(scene: &mut Scene) {
+    ///     if scene.time_since_start >= 1.0 {
+    ///         scene.request_exit();
+    ///     }
+    /// }
+    ///
+    /// let camera = Camera::new(0.1, 100.0, 1.0, Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), quit_after_one_second, blank_on_awake);
+    /// let mut scene = Scene::new(vec![], vec![], InputManager::new(), camera);
+    ///
+    /// engine_headless(&mut scene, 600);
+    ///
+    /// assert!(scene.exit_requested());
+    /// assert!((scene.time_since_start - 1.0).abs() < 0.02);
+    /// ```
+    pub fn request_exit(&mut self) {
+        self.window_requests.exit = true;
+    }
+
+    /// Whether `request_exit()` has been called.
+    pub fn exit_requested(&self) -> bool {
+        self.window_requests.exit
+    }
+
+    /// changes the title of the window at the end of the frame.
+    pub fn set_window_title(&mut self, title: &str) {
+        self.window_requests.title = Some(String::from(title));
+    }
+
+    /// switches the window between windowed, borderless and exclusive fullscreen at the end of the frame.
+    pub fn set_fullscreen(&mut self, window_mode: WindowMode) {
+        self.window_requests.window_mode = Some(window_mode);
+    }
+
+    /// shows or hides the mouse cursor while it is over the window at the end of the frame.
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        self.window_requests.cursor_visible = Some(visible);
+    }
+
+    /// confines the mouse cursor to the window, or releases it, at the end of the frame. Platforms that can not
+    /// confine the cursor lock it in place instead. Whether it worked can be read with `cursor_grabbed()`.
+    pub fn set_cursor_grab(&mut self, grab: bool) {
+        self.window_requests.cursor_grab = Some(grab);
+    }
+
+    /// Whether the mouse cursor is grabbed. Stays `false` after `set_cursor_grab(true)` if the platform can neither
+    /// confine nor lock the cursor, or if there is no window.
+    ///
+    /// # Examples
+    /// ```
+    /// # use copper_engine::{blank_on_awake, blank_tick_update, input::InputManager, math::Vector3, structs::Scene, Camera};
+    /// # let camera = Camera::new(0.1, 100.0, 1.0, Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), blank_tick_update, blank_on_awake);
+    /// use copper_engine::engine_headless;
+    ///
+    /// let mut scene = Scene::new(vec![], vec![], InputManager::new(), camera);
+    /// scene.set_cursor_grab(true);
+    /// engine_headless(&mut scene, 1);
+    ///
+    /// // Headless scenes have no window to grab the cursor in.
+    /// assert!(!scene.cursor_grabbed());
+    /// ```
+    pub fn cursor_grabbed(&self) -> bool {
+        self.window_requests.cursor_grabbed
+    }
+
+    /// resizes the window to `size` logical pixels, width as x and height as y, at the end of the frame.
+    pub fn set_window_size(&mut self, size: impl Into<UVector2>) {
+        self.window_requests.size = Some(size.into());
+    }
+
+    /// Computes the `WorldMatrix` of every entity with `Transform` from the local transforms, parents before their
+    /// children.
+    pub(crate) fn update_world_matrices(&mut self) {
+        let mut stack: Vec<(EntityId, Matrix4x4)> =
+            self.roots().map(|id| (id, Matrix4x4::identity())).collect();
+
+        while let Some((id, parent_matrix)) = stack.pop() {
+            let world_matrix = match self.get::<Transform>(id) {
+                Some(transform) => {
+                    let world_matrix = transform.to_matrix() * parent_matrix;
+                    self.insert(id, WorldMatrix(world_matrix));
+
+                    world_matrix
+                }
+                None => parent_matrix,
+            };
+
+            stack.extend(self.children(id).iter().map(|&child| (child, world_matrix)));
         }
     }
 }
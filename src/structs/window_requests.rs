@@ -0,0 +1,17 @@
+//! Window requests are changes to the window made from game code, applied by the engine once per frame.
+
+use crate::math::UVector2;
+use crate::WindowMode;
+
+/// Changes requested through the window methods of `Scene` since the last frame. `None` leaves the setting as is.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct WindowRequests {
+    pub(crate) exit: bool,
+    pub(crate) title: Option<String>,
+    pub(crate) window_mode: Option<WindowMode>,
+    pub(crate) cursor_visible: Option<bool>,
+    pub(crate) cursor_grab: Option<bool>,
+    pub(crate) size: Option<UVector2>,
+    /// Whether the last cursor grab request was applied, not a request itself.
+    pub(crate) cursor_grabbed: bool,
+}